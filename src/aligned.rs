@@ -0,0 +1,229 @@
+use super::{write_into, WriteInto};
+use std::io;
+
+/// I/O sink adapter aligning positions relative to a base offset.
+///
+/// Useful for nested sections of a format, which are aligned relative to their own start even
+/// when the start itself is not aligned in the underlying I/O sink.
+///
+/// # Example
+///
+/// ```
+/// use std::io;
+/// use write_into::{AlignedWriter, BigEndian, write_into};
+///
+/// let mut buffer = io::Cursor::new(Vec::new());
+/// write_into(&mut buffer, BigEndian(0xAAu8)).unwrap();
+/// let mut section = AlignedWriter::new(&mut buffer, 1);
+/// write_into(&mut section, BigEndian(0xBBu8)).unwrap();
+/// let aligned_position = section.align_to(4).unwrap();
+/// write_into(&mut section, BigEndian(0xCCu8)).unwrap();
+/// assert_eq!(aligned_position, 5);
+/// assert_eq!(buffer.get_ref(), &[0xAA, 0xBB, 0x00, 0x00, 0x00, 0xCC]);
+/// ```
+pub struct AlignedWriter<W>
+where
+    W: io::Write + io::Seek,
+{
+    sink: W,
+    base_offset: u64,
+}
+
+impl<W> AlignedWriter<W>
+where
+    W: io::Write + io::Seek,
+{
+    /// Wraps the I/O sink aligning positions relative to `base_offset`.
+    pub fn new(sink: W, base_offset: u64) -> Self {
+        Self { sink, base_offset }
+    }
+
+    /// Returns the offset alignment is relative to.
+    pub fn base_offset(&self) -> u64 {
+        self.base_offset
+    }
+
+    /// Aligns position in the I/O sink to the given boundary relative to the base offset and
+    /// returns a new position.
+    ///
+    /// Fails with [`io::ErrorKind::InvalidInput`] if the boundary is zero or the position is
+    /// before the base offset.
+    pub fn align_to(&mut self, boundary: u64) -> io::Result<u64> {
+        if boundary == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "alignment boundary is zero",
+            ));
+        }
+
+        let position = self.sink.stream_position()?;
+        let offset = position.checked_sub(self.base_offset).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "position is before the base offset",
+            )
+        })?;
+
+        let alignment = (boundary - offset % boundary) % boundary;
+        self.sink.seek(io::SeekFrom::Current(alignment as i64))
+    }
+
+    /// Returns the I/O sink.
+    pub fn into_inner(self) -> W {
+        self.sink
+    }
+}
+
+impl<W> io::Write for AlignedWriter<W>
+where
+    W: io::Write + io::Seek,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.sink.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.sink.flush()
+    }
+}
+
+impl<W> io::Seek for AlignedWriter<W>
+where
+    W: io::Write + io::Seek,
+{
+    fn seek(&mut self, position: io::SeekFrom) -> io::Result<u64> {
+        self.sink.seek(position)
+    }
+}
+
+/// Used to write values at positions aligned to a boundary, checked in debug builds.
+///
+/// The first field is the boundary. Since [`WriteInto`] cannot query positions of I/O sinks,
+/// the wrapper provides its own `write_into` method taking a seekable sink. In debug builds it
+/// panics if the position is not aligned, in release builds it just writes the value. Writing with
+/// zero boundary fails with [`io::ErrorKind::InvalidInput`] in all builds.
+///
+/// Note that `write_into` is an inherent method and not a [`WriteInto`] implementation, so the
+/// wrapper cannot be nested in tuples, [`Sized`](crate::Sized) and other wrappers.
+///
+/// # Example
+///
+/// ```
+/// use std::io;
+/// use write_into::{BigEndian, RequireAligned, align_position, write_into};
+///
+/// let mut buffer = io::Cursor::new(Vec::new());
+/// write_into(&mut buffer, BigEndian(0xAAu8)).unwrap();
+/// align_position(&mut buffer, 4).unwrap();
+/// let written = RequireAligned(4, BigEndian(0xBBu8)).write_into(&mut buffer).unwrap();
+/// assert_eq!(written, 1);
+/// assert_eq!(buffer.get_ref(), &[0xAA, 0x00, 0x00, 0x00, 0xBB]);
+/// ```
+pub struct RequireAligned<T>(pub u64, pub T)
+where
+    T: WriteInto;
+
+impl<T> RequireAligned<T>
+where
+    T: WriteInto,
+{
+    /// Writes the value into the I/O sink, asserting in debug builds that its position is
+    /// aligned.
+    pub fn write_into<W>(self, sink: &mut W) -> io::Result<T::Output>
+    where
+        W: io::Write + io::Seek,
+    {
+        if self.0 == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "alignment boundary is zero",
+            ));
+        }
+
+        if cfg!(debug_assertions) {
+            let position = sink.stream_position()?;
+            assert!(
+                position % self.0 == 0,
+                "position {} is not aligned to {} bytes",
+                position,
+                self.0,
+            );
+        }
+
+        write_into(sink, self.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+
+    #[test]
+    fn align_relative_to_base() {
+        let mut buffer = io::Cursor::new(vec![0xFF; 3]);
+        buffer.set_position(3);
+        let mut section = AlignedWriter::new(&mut buffer, 3);
+        write_into(&mut section, Plain("abc")).unwrap();
+        assert_eq!(section.align_to(8).unwrap(), 11);
+        write_into(&mut section, Plain("d")).unwrap();
+        assert_eq!(buffer.get_ref(), b"\xFF\xFF\xFFabc\0\0\0\0\0d");
+    }
+
+    #[test]
+    fn align_when_already_aligned() {
+        let mut buffer = io::Cursor::new(Vec::new());
+        write_into(&mut buffer, Plain("abcde")).unwrap();
+        let mut section = AlignedWriter::new(&mut buffer, 1);
+        assert_eq!(section.align_to(4).unwrap(), 5);
+        assert_eq!(section.align_to(2).unwrap(), 5);
+    }
+
+    #[test]
+    fn align_nested_sections() {
+        let mut buffer = io::Cursor::new(Vec::new());
+        write_into(&mut buffer, Plain("ab")).unwrap();
+        let mut outer = AlignedWriter::new(&mut buffer, 2);
+        write_into(&mut outer, Plain("c")).unwrap();
+        let mut inner = AlignedWriter::new(&mut outer, 3);
+        write_into(&mut inner, Plain("d")).unwrap();
+        assert_eq!(inner.align_to(4).unwrap(), 7);
+        assert_eq!(outer.align_to(4).unwrap(), 10);
+    }
+
+    #[test]
+    fn align_before_base() {
+        let mut buffer = io::Cursor::new(Vec::new());
+        let mut section = AlignedWriter::new(&mut buffer, 4);
+        let error = section.align_to(4).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn require_aligned_when_aligned() {
+        let mut buffer = io::Cursor::new(Vec::new());
+        write_into(&mut buffer, Plain("abcd")).unwrap();
+        RequireAligned(4, Plain("efgh"))
+            .write_into(&mut buffer)
+            .unwrap();
+        assert_eq!(buffer.get_ref(), b"abcdefgh");
+    }
+
+    #[test]
+    fn require_aligned_with_zero_boundary() {
+        let mut buffer = io::Cursor::new(Vec::new());
+        let error = RequireAligned(0, Plain("abcd"))
+            .write_into(&mut buffer)
+            .unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert!(buffer.get_ref().is_empty());
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "position 3 is not aligned to 4 bytes")]
+    fn require_aligned_when_misaligned() {
+        let mut buffer = io::Cursor::new(Vec::new());
+        write_into(&mut buffer, Plain("abc")).unwrap();
+        let _ = RequireAligned(4, Plain("d")).write_into(&mut buffer);
+    }
+}
@@ -0,0 +1,246 @@
+use super::plain::slice_as_bytes;
+use super::{write_into, BigEndian, LittleEndian, Plain, Sleb128, Uleb128, WriteInto};
+use std::io;
+use std::slice::from_ref;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+/// Writes value into asynchronous I/O sink _(requires `tokio` feature)_.
+///
+/// Mirrors [`WriteInto`] for [`AsyncWrite`] sinks. [`Plain`] values are written straight from
+/// their memory, other representations are produced by the synchronous implementation into a
+/// scratch buffer. Either way they are written with a single [`AsyncWriteExt::write_all`] call.
+///
+/// # Example
+///
+/// ```
+/// use write_into::{AsyncWriteInto, BigEndian};
+///
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// let mut buffer = Vec::new();
+/// let written = BigEndian(0xCAFEBABEu32).write_into(&mut buffer).await.unwrap();
+/// assert_eq!(written, 4);
+/// assert_eq!(&buffer, &[0xCA, 0xFE, 0xBA, 0xBE]);
+/// # });
+/// ```
+#[allow(async_fn_in_trait)]
+pub trait AsyncWriteInto {
+    /// Result of [`AsyncWriteInto::write_into`] function (e.g. `()` or [`usize`]).
+    type Output;
+
+    /// Writes value into asynchronous I/O sink.
+    async fn write_into(self, sink: &mut (impl AsyncWrite + Unpin)) -> io::Result<Self::Output>;
+}
+
+/// An alias for [`AsyncWriteInto::write_into`] for writing
+/// `write_into_async(sink, Wrapper(...)).await` instead of `Wrapper(...).write_into(sink).await`
+/// _(requires `tokio` feature)_.
+///
+/// # Example
+///
+/// ```
+/// use write_into::{Plain, Uleb128, write_into_async};
+///
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// let mut buffer = Vec::new();
+/// write_into_async(&mut buffer, Uleb128(5u32)).await.unwrap();
+/// write_into_async(&mut buffer, Plain("Hello")).await.unwrap();
+/// assert_eq!(&buffer, b"\x05Hello");
+/// # });
+/// ```
+#[inline]
+pub async fn write_into_async<T: AsyncWriteInto>(
+    sink: &mut (impl AsyncWrite + Unpin),
+    value: T,
+) -> io::Result<T::Output> {
+    value.write_into(sink).await
+}
+
+macro_rules! impl_async_write_into {
+    ($($wrapper:ident)*) => {
+        $(
+            impl<T> AsyncWriteInto for $wrapper<T>
+            where
+                $wrapper<T>: WriteInto,
+            {
+                type Output = <$wrapper<T> as WriteInto>::Output;
+
+                async fn write_into(
+                    self,
+                    sink: &mut (impl AsyncWrite + Unpin),
+                ) -> io::Result<Self::Output> {
+                    write_buffered(sink, self).await
+                }
+            }
+
+            impl<'a, T> AsyncWriteInto for &'a $wrapper<T>
+            where
+                &'a $wrapper<T>: WriteInto,
+            {
+                type Output = <&'a $wrapper<T> as WriteInto>::Output;
+
+                async fn write_into(
+                    self,
+                    sink: &mut (impl AsyncWrite + Unpin),
+                ) -> io::Result<Self::Output> {
+                    write_buffered(sink, self).await
+                }
+            }
+        )*
+    };
+}
+
+impl_async_write_into! {
+    BigEndian LittleEndian Sleb128 Uleb128
+}
+
+impl<T> AsyncWriteInto for Plain<&T> {
+    type Output = ();
+
+    async fn write_into(self, sink: &mut (impl AsyncWrite + Unpin)) -> io::Result<Self::Output> {
+        sink.write_all(slice_as_bytes(from_ref(self.0))).await
+    }
+}
+
+impl<T> AsyncWriteInto for &Plain<&T> {
+    type Output = ();
+
+    async fn write_into(self, sink: &mut (impl AsyncWrite + Unpin)) -> io::Result<Self::Output> {
+        write_into_async(sink, Plain(self.0)).await
+    }
+}
+
+impl<T> AsyncWriteInto for Plain<&[T]> {
+    type Output = ();
+
+    async fn write_into(self, sink: &mut (impl AsyncWrite + Unpin)) -> io::Result<Self::Output> {
+        sink.write_all(slice_as_bytes(self.0)).await
+    }
+}
+
+impl<T> AsyncWriteInto for &Plain<&[T]> {
+    type Output = ();
+
+    async fn write_into(self, sink: &mut (impl AsyncWrite + Unpin)) -> io::Result<Self::Output> {
+        write_into_async(sink, Plain(self.0)).await
+    }
+}
+
+impl AsyncWriteInto for Plain<&str> {
+    type Output = ();
+
+    async fn write_into(self, sink: &mut (impl AsyncWrite + Unpin)) -> io::Result<Self::Output> {
+        sink.write_all(self.0.as_bytes()).await
+    }
+}
+
+impl AsyncWriteInto for &Plain<&str> {
+    type Output = ();
+
+    async fn write_into(self, sink: &mut (impl AsyncWrite + Unpin)) -> io::Result<Self::Output> {
+        write_into_async(sink, Plain(self.0)).await
+    }
+}
+
+macro_rules! impl_async_write_into_plain {
+    ($($primitive:ty)*) => {
+        $(
+            impl AsyncWriteInto for Plain<$primitive> {
+                type Output = ();
+
+                async fn write_into(
+                    self,
+                    sink: &mut (impl AsyncWrite + Unpin),
+                ) -> io::Result<Self::Output> {
+                    write_into_async(sink, Plain(&self.0)).await
+                }
+            }
+
+            impl AsyncWriteInto for &Plain<$primitive> {
+                type Output = ();
+
+                async fn write_into(
+                    self,
+                    sink: &mut (impl AsyncWrite + Unpin),
+                ) -> io::Result<Self::Output> {
+                    write_into_async(sink, Plain(&self.0)).await
+                }
+            }
+        )*
+    };
+}
+
+impl_async_write_into_plain! {
+    i8 i16 i32 i64 i128 isize
+    u8 u16 u32 u64 u128 usize
+    bool char f32 f64
+}
+
+async fn write_buffered<T: WriteInto>(
+    sink: &mut (impl AsyncWrite + Unpin),
+    value: T,
+) -> io::Result<T::Output> {
+    let mut buffer = Vec::new();
+    let output = write_into(&mut buffer, value)?;
+    sink.write_all(&buffer).await?;
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn write_big_endian() {
+        let mut buffer = Vec::new();
+        let written = write_into_async(&mut buffer, BigEndian(0xCAFEBABEu32))
+            .await
+            .unwrap();
+        assert_eq!(written, 4);
+        assert_eq!(&buffer, &[0xCA, 0xFE, 0xBA, 0xBE]);
+    }
+
+    #[tokio::test]
+    async fn write_matches_sync() {
+        let mut expected = Vec::new();
+        write_into(&mut expected, LittleEndian(&[1u16, 2][..])).unwrap();
+        write_into(&mut expected, &Sleb128(-123456i32)).unwrap();
+        write_into(&mut expected, Uleb128(624485u32)).unwrap();
+        write_into(&mut expected, Plain("Hello")).unwrap();
+
+        let mut buffer = Vec::new();
+        write_into_async(&mut buffer, LittleEndian(&[1u16, 2][..]))
+            .await
+            .unwrap();
+        write_into_async(&mut buffer, &Sleb128(-123456i32))
+            .await
+            .unwrap();
+        write_into_async(&mut buffer, Uleb128(624485u32))
+            .await
+            .unwrap();
+        write_into_async(&mut buffer, Plain("Hello")).await.unwrap();
+        assert_eq!(buffer, expected);
+    }
+
+    #[tokio::test]
+    async fn write_plain_without_buffering() {
+        let bytes: &[u8] = b"\x01\x02";
+        let mut buffer = Vec::new();
+        write_into_async(&mut buffer, Plain(bytes)).await.unwrap();
+        write_into_async(&mut buffer, &Plain("ab")).await.unwrap();
+        write_into_async(&mut buffer, Plain(&[0x0304u16][..]))
+            .await
+            .unwrap();
+        write_into_async(&mut buffer, Plain(b'c')).await.unwrap();
+        write_into_async(&mut buffer, &Plain(&0x05u8))
+            .await
+            .unwrap();
+
+        let mut expected = Vec::new();
+        write_into(&mut expected, Plain(bytes)).unwrap();
+        write_into(&mut expected, Plain("ab")).unwrap();
+        write_into(&mut expected, Plain(&[0x0304u16][..])).unwrap();
+        write_into(&mut expected, Plain(b'c')).unwrap();
+        write_into(&mut expected, Plain(&0x05u8)).unwrap();
+        assert_eq!(buffer, expected);
+    }
+}
@@ -0,0 +1,103 @@
+use super::{io, write_into, Plain, WriteInto};
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// Used to write byte slices in Base32 encoding (RFC 4648).
+///
+/// The second field tells whether the last group of characters is padded with `=` to 8
+/// characters.
+///
+/// # Example
+///
+/// ```
+/// use write_into::{Base32, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, Base32(b"foo", true)).unwrap();
+/// assert_eq!(written, 8);
+/// assert_eq!(&buffer, b"MZXW6===");
+/// ```
+pub struct Base32<'a>(pub &'a [u8], pub bool);
+
+/// Returns how many characters was written.
+impl WriteInto for Base32<'_> {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        const ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+        let mut encoded = Vec::with_capacity(self.0.len().div_ceil(5) * 8);
+        for chunk in self.0.chunks(5) {
+            let mut group = [0u8; 5];
+            group[..chunk.len()].copy_from_slice(chunk);
+            let bits = group
+                .iter()
+                .fold(0u64, |bits, &byte| bits << 8 | byte as u64);
+
+            // Each started 5-bit character carries some of the input bits.
+            let characters = (chunk.len() * 8).div_ceil(5);
+            for index in 0..characters {
+                let shift = 35 - index * 5;
+                encoded.push(ALPHABET[(bits >> shift & 0x1F) as usize]);
+            }
+
+            if self.1 {
+                encoded.resize(encoded.len() + 8 - characters, b'=');
+            }
+        }
+
+        write_into(sink, Plain(&encoded[..]))?;
+        Ok(encoded.len())
+    }
+}
+
+/// Returns how many characters was written.
+impl WriteInto for &Base32<'_> {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, Base32(self.0, self.1))
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::super::*;
+    use test_case::test_case;
+    use validators::vec;
+
+    mod validators {
+        pub fn vec(expected: &[u8]) -> impl FnOnce(Vec<u8>) {
+            let expected = expected.to_vec();
+            move |actual| assert_eq!(&expected, &actual)
+        }
+    }
+
+    #[test_case( b""       => using vec(b""                ); "when empty"  )]
+    #[test_case( b"f"      => using vec(b"MY======"        ); "when f"      )]
+    #[test_case( b"fo"     => using vec(b"MZXQ===="        ); "when fo"     )]
+    #[test_case( b"foo"    => using vec(b"MZXW6==="        ); "when foo"    )]
+    #[test_case( b"foob"   => using vec(b"MZXW6YQ="        ); "when foob"   )]
+    #[test_case( b"fooba"  => using vec(b"MZXW6YTB"        ); "when fooba"  )]
+    #[test_case( b"foobar" => using vec(b"MZXW6YTBOI======"); "when foobar" )]
+    fn write_padded(bytes: &[u8]) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, Base32(bytes, true)).unwrap();
+        assert_eq!(written, buffer.len());
+        buffer
+    }
+
+    #[test_case( b""       => using vec(b""          ); "when empty"  )]
+    #[test_case( b"f"      => using vec(b"MY"        ); "when f"      )]
+    #[test_case( b"fo"     => using vec(b"MZXQ"      ); "when fo"     )]
+    #[test_case( b"foo"    => using vec(b"MZXW6"     ); "when foo"    )]
+    #[test_case( b"foob"   => using vec(b"MZXW6YQ"   ); "when foob"   )]
+    #[test_case( b"fooba"  => using vec(b"MZXW6YTB"  ); "when fooba"  )]
+    #[test_case( b"foobar" => using vec(b"MZXW6YTBOI"); "when foobar" )]
+    fn write_unpadded(bytes: &[u8]) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, Base32(bytes, false)).unwrap();
+        assert_eq!(written, buffer.len());
+        buffer
+    }
+}
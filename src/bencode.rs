@@ -0,0 +1,100 @@
+use super::{write_into, Plain, WriteInto};
+use std::io;
+
+/// Used to write integers in bencode format (`i<number>e`).
+///
+/// # Example
+///
+/// ```
+/// use write_into::{BencodeInt, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, BencodeInt(-3)).unwrap();
+/// assert_eq!(written, 4);
+/// assert_eq!(&buffer, b"i-3e");
+/// ```
+pub struct BencodeInt(pub i64);
+
+/// Returns how many bytes was written.
+impl WriteInto for BencodeInt {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let text = format!("i{}e", self.0);
+        write_into(sink, Plain(&*text))?;
+        Ok(text.len())
+    }
+}
+
+/// Returns how many bytes was written.
+impl WriteInto for &BencodeInt {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, BencodeInt(self.0))
+    }
+}
+
+/// Used to write byte strings in bencode format (`<length>:<bytes>`).
+///
+/// # Example
+///
+/// ```
+/// use write_into::{BencodeBytes, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, BencodeBytes(b"announce")).unwrap();
+/// assert_eq!(written, 10);
+/// assert_eq!(&buffer, b"8:announce");
+/// ```
+pub struct BencodeBytes<'a>(pub &'a [u8]);
+
+/// Returns how many bytes was written.
+impl WriteInto for BencodeBytes<'_> {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let prefix = format!("{}:", self.0.len());
+        write_into(sink, Plain(&*prefix))?;
+        write_into(sink, Plain(self.0))?;
+        Ok(prefix.len() + self.0.len())
+    }
+}
+
+/// Returns how many bytes was written.
+impl WriteInto for &BencodeBytes<'_> {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, BencodeBytes(self.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+
+    #[test]
+    fn write_int() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, BencodeInt(42)).unwrap();
+        assert_eq!(written, 4);
+        assert_eq!(&buffer, b"i42e");
+    }
+
+    #[test]
+    fn write_bytes() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, BencodeBytes(b"spam")).unwrap();
+        assert_eq!(written, 6);
+        assert_eq!(&buffer, b"4:spam");
+    }
+
+    #[test]
+    fn write_empty_bytes() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, BencodeBytes(b"")).unwrap();
+        assert_eq!(written, 2);
+        assert_eq!(&buffer, b"0:");
+    }
+}
@@ -0,0 +1,274 @@
+use std::io;
+
+/// I/O sink adapter that packs bits MSB-first into bytes.
+///
+/// Bits are accumulated in a pending byte, which is written into the underlying I/O sink once
+/// it is full. A partial byte is only written (padded with zeros) by [`BitWriter::flush`].
+///
+/// # Example
+///
+/// ```
+/// use write_into::{Bit, BitWriter, write_into_bits};
+///
+/// let mut buffer = Vec::new();
+/// let mut sink = BitWriter::new(&mut buffer);
+/// write_into_bits(&mut sink, Bit(true)).unwrap();
+/// write_into_bits(&mut sink, Bit(true)).unwrap();
+/// sink.flush().unwrap();
+/// assert_eq!(&buffer, &[0b1100_0000]);
+/// ```
+pub struct BitWriter<W>
+where
+    W: io::Write,
+{
+    sink: W,
+    pending: u8,
+    pending_bits: u8,
+}
+
+impl<W> BitWriter<W>
+where
+    W: io::Write,
+{
+    /// Wraps the I/O sink.
+    pub fn new(sink: W) -> Self {
+        Self {
+            sink,
+            pending: 0,
+            pending_bits: 0,
+        }
+    }
+
+    /// Writes a single bit.
+    ///
+    /// If writing a completed byte into the I/O sink fails, the byte is discarded and the next bit
+    /// starts a new one.
+    pub fn write_bit(&mut self, bit: bool) -> io::Result<()> {
+        self.pending = self.pending << 1 | bit as u8;
+        self.pending_bits += 1;
+        if self.pending_bits == 8 {
+            let byte = self.pending;
+            self.pending = 0;
+            self.pending_bits = 0;
+            self.sink.write_all(&[byte])?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the lowest `count` bits of the value, most significant first.
+    ///
+    /// Fails with [`io::ErrorKind::InvalidInput`] if the count is greater than 64.
+    pub fn write_bits(&mut self, value: u64, count: u8) -> io::Result<()> {
+        if count > 64 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "bit count is greater than 64",
+            ));
+        }
+
+        for shift in (0..count).rev() {
+            self.write_bit(value >> shift & 1 != 0)?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the pending partial byte padded with zeros and flushes the I/O sink.
+    pub fn flush(&mut self) -> io::Result<()> {
+        if self.pending_bits != 0 {
+            self.sink
+                .write_all(&[self.pending << (8 - self.pending_bits)])?;
+            self.pending = 0;
+            self.pending_bits = 0;
+        }
+
+        self.sink.flush()
+    }
+
+    /// Returns the I/O sink discarding the pending partial byte.
+    pub fn into_inner(self) -> W {
+        self.sink
+    }
+}
+
+/// Writes value into bit sink.
+///
+/// A counterpart of [`WriteInto`](super::WriteInto) for values which are not byte-aligned.
+pub trait WriteIntoBits {
+    /// Result of [`WriteIntoBits::write_into_bits`] function.
+    type Output;
+
+    /// Writes value into bit sink.
+    fn write_into_bits<W: io::Write>(self, sink: &mut BitWriter<W>) -> io::Result<Self::Output>;
+}
+
+/// An alias for [`WriteIntoBits::write_into_bits`] for writing `write_into_bits(sink, Wrapper(...))`
+/// instead of `Wrapper(...).write_into_bits(sink)`.
+#[inline]
+pub fn write_into_bits<T: WriteIntoBits, W: io::Write>(
+    sink: &mut BitWriter<W>,
+    value: T,
+) -> io::Result<T::Output> {
+    value.write_into_bits(sink)
+}
+
+/// Used to write booleans as single bits.
+///
+/// # Example
+///
+/// ```
+/// use write_into::{Bit, BitWriter, write_into_bits};
+///
+/// let mut buffer = Vec::new();
+/// let mut sink = BitWriter::new(&mut buffer);
+/// for bit in [false, true, false, false, false, false, false, true] {
+///     write_into_bits(&mut sink, Bit(bit)).unwrap();
+/// }
+/// assert_eq!(&buffer, b"A");
+/// ```
+pub struct Bit(pub bool);
+
+impl WriteIntoBits for Bit {
+    type Output = ();
+
+    fn write_into_bits<W: io::Write>(self, sink: &mut BitWriter<W>) -> io::Result<Self::Output> {
+        sink.write_bit(self.0)
+    }
+}
+
+impl WriteIntoBits for &Bit {
+    type Output = ();
+
+    fn write_into_bits<W: io::Write>(self, sink: &mut BitWriter<W>) -> io::Result<Self::Output> {
+        write_into_bits(sink, Bit(self.0))
+    }
+}
+
+/// Used to write the lowest bits of unsigned integers, most significant first.
+///
+/// The second field is the number of bits. Writing more bits than the integer has fails with
+/// [`io::ErrorKind::InvalidInput`].
+///
+/// # Example
+///
+/// ```
+/// use write_into::{BitWriter, Bits, write_into_bits};
+///
+/// let mut buffer = Vec::new();
+/// let mut sink = BitWriter::new(&mut buffer);
+/// write_into_bits(&mut sink, Bits(0b101u8, 3)).unwrap();
+/// write_into_bits(&mut sink, Bits(0b1u8, 1)).unwrap();
+/// sink.flush().unwrap();
+/// assert_eq!(&buffer, &[0b1011_0000]);
+/// ```
+pub struct Bits<T>(pub T, pub u8);
+
+macro_rules! impl_write_into_bits {
+    ($($primitive:ident)*) => {
+        $(
+            impl WriteIntoBits for Bits<$primitive> {
+                type Output = ();
+
+                fn write_into_bits<W: io::Write>(
+                    self,
+                    sink: &mut BitWriter<W>,
+                ) -> io::Result<Self::Output> {
+                    if self.1 as u32 > $primitive::BITS {
+                        return Err(io::Error::new(
+                            io::ErrorKind::InvalidInput,
+                            "bit count is greater than the width of the integer",
+                        ));
+                    }
+
+                    sink.write_bits(self.0 as u64, self.1)
+                }
+            }
+
+            impl WriteIntoBits for &Bits<$primitive> {
+                type Output = ();
+
+                fn write_into_bits<W: io::Write>(
+                    self,
+                    sink: &mut BitWriter<W>,
+                ) -> io::Result<Self::Output> {
+                    write_into_bits(sink, Bits(self.0, self.1))
+                }
+            }
+        )*
+    };
+}
+
+impl_write_into_bits! {
+    u8 u16 u32 u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::CappedSink;
+    use super::*;
+
+    #[test]
+    fn write_high_nibble() {
+        let mut buffer = Vec::new();
+        let mut sink = BitWriter::new(&mut buffer);
+        for bit in [true, false, true, true] {
+            write_into_bits(&mut sink, Bit(bit)).unwrap();
+        }
+        sink.flush().unwrap();
+        assert_eq!(&buffer, &[0b1011_0000]);
+    }
+
+    #[test]
+    fn write_across_byte_boundary() {
+        let mut buffer = Vec::new();
+        let mut sink = BitWriter::new(&mut buffer);
+        for bit in [true; 9] {
+            write_into_bits(&mut sink, Bit(bit)).unwrap();
+        }
+        let buffer = sink.into_inner();
+        assert_eq!(buffer, &[0xFF]);
+    }
+
+    #[test]
+    fn write_bits_across_byte_boundary() {
+        let mut buffer = Vec::new();
+        let mut sink = BitWriter::new(&mut buffer);
+        sink.write_bits(0b101, 3).unwrap();
+        sink.write_bits(0b110011, 6).unwrap();
+        sink.flush().unwrap();
+        assert_eq!(&buffer, &[0b1011_1001, 0b1000_0000]);
+    }
+
+    #[test]
+    fn write_bits_wrapper() {
+        let mut buffer = Vec::new();
+        let mut sink = BitWriter::new(&mut buffer);
+        write_into_bits(&mut sink, Bits(0b101u8, 3)).unwrap();
+        write_into_bits(&mut sink, &Bits(0xABCDu16, 16)).unwrap();
+        write_into_bits(&mut sink, Bits(u64::MAX, 5)).unwrap();
+        sink.flush().unwrap();
+        assert_eq!(&buffer, &[0b1011_0101, 0b0111_1001, 0b1011_1111]);
+    }
+
+    #[test]
+    fn write_too_many_bits() {
+        let mut buffer = Vec::new();
+        let mut sink = BitWriter::new(&mut buffer);
+        let error = write_into_bits(&mut sink, Bits(0u8, 9)).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        let error = sink.write_bits(0, 65).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn write_after_failed_byte() {
+        let mut sink = BitWriter::new(CappedSink::new(Vec::new(), 0));
+        let error = sink.write_bits(0xFF, 8).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::WriteZero);
+        sink.write_bit(true).unwrap();
+        let error = sink.flush().unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::WriteZero);
+        assert!(sink.into_inner().into_inner().is_empty());
+    }
+}
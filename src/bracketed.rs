@@ -0,0 +1,66 @@
+use super::{write_into, WriteInto};
+use std::io;
+
+/// Used to write values surrounded by a magic value on both sides.
+///
+/// The magic value is written twice, so it is usually passed by reference.
+///
+/// # Example
+///
+/// ```
+/// use write_into::{BigEndian, Bracketed, Plain, write_into};
+///
+/// let mut buffer = Vec::new();
+/// write_into(&mut buffer, Bracketed(&BigEndian(0x7E7Eu16), Plain("frame"))).unwrap();
+/// assert_eq!(&buffer, b"\x7E\x7Eframe\x7E\x7E");
+/// ```
+pub struct Bracketed<M, T>(pub M, pub T)
+where
+    M: Clone + WriteInto,
+    T: WriteInto;
+
+/// Returns the output of writing `T`.
+impl<M, T> WriteInto for Bracketed<M, T>
+where
+    M: Clone + WriteInto,
+    T: WriteInto,
+{
+    type Output = T::Output;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, self.0.clone())?;
+        let output = write_into(sink, self.1)?;
+        write_into(sink, self.0)?;
+        Ok(output)
+    }
+}
+
+/// Returns the output of writing `T`.
+impl<M, T> WriteInto for &Bracketed<M, T>
+where
+    M: Clone + WriteInto,
+    T: Copy + WriteInto,
+{
+    type Output = T::Output;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, Bracketed(self.0.clone(), self.1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+
+    #[test]
+    fn write_bracketed() {
+        let mut buffer = Vec::new();
+        let written = write_into(
+            &mut buffer,
+            Bracketed(&Plain(b"\xCA\xFE"), Sized(Uleb128, Plain("body"))),
+        )
+        .unwrap();
+        assert_eq!(written, (4, ()));
+        assert_eq!(&buffer, b"\xCA\xFE\x04body\xCA\xFE");
+    }
+}
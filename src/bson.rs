@@ -0,0 +1,86 @@
+use super::{io, write_into, LittleEndian, Plain, WriteInto};
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// Used to write values as bodies of BSON documents.
+///
+/// The body is prepended with the total length of the document as little endian [`i32`] and
+/// followed by a zero byte. The length includes both the length field itself and the trailing
+/// zero byte. Writing a document longer than [`i32::MAX`] bytes fails with
+/// [`io::ErrorKind::InvalidInput`].
+///
+/// # Example
+///
+/// ```
+/// use write_into::{BsonDocument, Plain, write_into};
+///
+/// // {"a": true}
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, BsonDocument(Plain(b"\x08a\0\x01"))).unwrap();
+/// assert_eq!(written, 9);
+/// assert_eq!(&buffer, b"\x09\0\0\0\x08a\0\x01\0");
+/// ```
+pub struct BsonDocument<T>(pub T)
+where
+    T: WriteInto;
+
+/// Returns how many bytes was written (the length of the document).
+impl<T> WriteInto for BsonDocument<T>
+where
+    T: WriteInto,
+{
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let mut buffer = Vec::new();
+        write_into(&mut buffer, self.0)?;
+        let written = buffer.len() + 5;
+        let length = i32::try_from(written).map_err(|_| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "document is longer than i32::MAX bytes",
+            )
+        })?;
+
+        write_into(sink, LittleEndian(length))?;
+        write_into(sink, Plain(&buffer[..]))?;
+        write_into(sink, Plain(&0u8))?;
+        Ok(written)
+    }
+}
+
+/// Returns how many bytes was written (the length of the document).
+impl<T> WriteInto for &BsonDocument<T>
+where
+    T: Copy + WriteInto,
+{
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, BsonDocument(self.0))
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::super::*;
+
+    #[test]
+    fn write_empty_document() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, BsonDocument(Plain(""))).unwrap();
+        assert_eq!(written, 5);
+        assert_eq!(&buffer, &[0x05, 0x00, 0x00, 0x00, 0x00]);
+    }
+
+    #[test]
+    fn write_document_with_string() {
+        // {"hello": "world"}
+        let body = (Plain(b"\x02hello\0"), LittleEndian(6i32), Plain(b"world\0"));
+
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, BsonDocument(body)).unwrap();
+        assert_eq!(written, 22);
+        assert_eq!(&buffer, b"\x16\0\0\0\x02hello\0\x06\0\0\0world\0\0");
+    }
+}
@@ -0,0 +1,169 @@
+use super::{write_into, WriteInto};
+use std::io;
+
+/// I/O sink adapter that accepts at most the given number of bytes.
+///
+/// Bytes over the limit are not written into the underlying I/O sink. Once the limit is
+/// exhausted, writing fails with [`io::ErrorKind::WriteZero`].
+///
+/// # Example
+///
+/// ```
+/// use std::io;
+/// use write_into::{CappedSink, Plain, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let mut sink = CappedSink::new(&mut buffer, 4);
+/// let error = write_into(&mut sink, Plain("Hello")).unwrap_err();
+/// assert_eq!(error.kind(), io::ErrorKind::WriteZero);
+/// assert_eq!(&buffer, b"Hell");
+/// ```
+pub struct CappedSink<W>
+where
+    W: io::Write,
+{
+    sink: W,
+    remaining: usize,
+}
+
+impl<W> CappedSink<W>
+where
+    W: io::Write,
+{
+    /// Wraps the I/O sink limiting it to `cap` bytes.
+    pub fn new(sink: W, cap: usize) -> Self {
+        Self {
+            sink,
+            remaining: cap,
+        }
+    }
+
+    /// Returns how many bytes can still be written.
+    pub fn remaining(&self) -> usize {
+        self.remaining
+    }
+
+    /// Returns the I/O sink.
+    pub fn into_inner(self) -> W {
+        self.sink
+    }
+}
+
+impl<W> io::Write for CappedSink<W>
+where
+    W: io::Write,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.remaining == 0 && !buf.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::WriteZero,
+                "size cap of the sink is exhausted",
+            ));
+        }
+
+        let accepted = buf.len().min(self.remaining);
+        let written = self.sink.write(&buf[..accepted])?;
+        self.remaining -= written;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.sink.flush()
+    }
+}
+
+/// Used to write values with a limit on the size of their representation.
+///
+/// The second field is the byte budget. Writing a value whose representation exceeds the budget
+/// is aborted with [`io::ErrorKind::WriteZero`] as soon as the budget is exhausted, so only the
+/// first bytes of the representation (up to the budget) reach the I/O sink.
+///
+/// # Example
+///
+/// ```
+/// use std::io;
+/// use write_into::{Guarded, Plain, write_into};
+///
+/// let mut buffer = Vec::new();
+/// write_into(&mut buffer, Guarded(Plain("Hi"), 4)).unwrap();
+/// let error = write_into(&mut buffer, Guarded(Plain("Hello"), 4)).unwrap_err();
+/// assert_eq!(error.kind(), io::ErrorKind::WriteZero);
+/// ```
+pub struct Guarded<T>(pub T, pub u64)
+where
+    T: WriteInto;
+
+/// Returns the output of writing `T`.
+impl<T> WriteInto for Guarded<T>
+where
+    T: WriteInto,
+{
+    type Output = T::Output;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let cap = usize::try_from(self.1).unwrap_or(usize::MAX);
+        write_into(&mut CappedSink::new(sink, cap), self.0)
+    }
+}
+
+/// Returns the output of writing `T`.
+impl<T> WriteInto for &Guarded<T>
+where
+    T: Copy + WriteInto,
+{
+    type Output = T::Output;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, Guarded(self.0, self.1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+
+    #[test]
+    fn write_under_cap() {
+        let mut buffer = Vec::new();
+        let mut sink = CappedSink::new(&mut buffer, 16);
+        write_into(&mut sink, BigEndian(0xCAFEBABEu32)).unwrap();
+        assert_eq!(sink.remaining(), 12);
+        assert_eq!(&buffer, &[0xCA, 0xFE, 0xBA, 0xBE]);
+    }
+
+    #[test]
+    fn write_exactly_at_cap() {
+        let mut buffer = Vec::new();
+        let mut sink = CappedSink::new(&mut buffer, 4);
+        write_into(&mut sink, BigEndian(0xCAFEBABEu32)).unwrap();
+        assert_eq!(sink.remaining(), 0);
+        assert_eq!(&buffer, &[0xCA, 0xFE, 0xBA, 0xBE]);
+    }
+
+    #[test]
+    fn write_over_cap() {
+        let mut buffer = Vec::new();
+        let mut sink = CappedSink::new(&mut buffer, 3);
+        let error = write_into(&mut sink, BigEndian(0xCAFEBABEu32)).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::WriteZero);
+        assert_eq!(&buffer, &[0xCA, 0xFE, 0xBA]);
+    }
+
+    #[test]
+    fn write_guarded_under_budget() {
+        let mut buffer = Vec::new();
+        let written =
+            write_into(&mut buffer, Guarded(Sequence((0..4u32).map(BigEndian)), 16)).unwrap();
+        assert_eq!(written, 4);
+        assert_eq!(buffer.len(), 16);
+    }
+
+    #[test]
+    fn write_guarded_over_budget() {
+        let items = (0..1_000_000u32).map(BigEndian);
+        let mut buffer = Vec::new();
+        let error = write_into(&mut buffer, Guarded(Sequence(items), 1024)).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::WriteZero);
+        assert_eq!(buffer.len(), 1024);
+    }
+}
@@ -0,0 +1,80 @@
+use super::{io, write_into, WriteInto};
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// Writes value into I/O sink and returns a copy of its representation along with the output.
+///
+/// # Example
+///
+/// ```
+/// use write_into::{BigEndian, write_into_capturing};
+///
+/// let mut buffer = vec![0xFF];
+/// let (captured, written) = write_into_capturing(&mut buffer, BigEndian(0xCAFEu16)).unwrap();
+/// assert_eq!(written, 2);
+/// assert_eq!(&captured, &[0xCA, 0xFE]);
+/// assert_eq!(&buffer, &[0xFF, 0xCA, 0xFE]);
+/// ```
+pub fn write_into_capturing<T>(
+    sink: &mut impl io::Write,
+    value: T,
+) -> io::Result<(Vec<u8>, T::Output)>
+where
+    T: WriteInto,
+{
+    let mut sink = CapturingSink {
+        sink,
+        captured: Vec::new(),
+    };
+
+    let output = write_into(&mut sink, value)?;
+    Ok((sink.captured, output))
+}
+
+struct CapturingSink<W> {
+    sink: W,
+    captured: Vec<u8>,
+}
+
+impl<W> io::Write for CapturingSink<W>
+where
+    W: io::Write,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.sink.write(buf)?;
+        self.captured.extend_from_slice(&buf[..written]);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.sink.flush()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::super::*;
+
+    #[test]
+    fn capture_matches_sink() {
+        let mut sink = io::Cursor::new(Vec::new());
+        let (captured, written) = write_into_capturing(
+            &mut sink,
+            Sized(|size| BigEndian(size as u16), Plain("Hello")),
+        )
+        .unwrap();
+        assert_eq!(written, (5, ()));
+        assert_eq!(&captured, sink.get_ref());
+        assert_eq!(&captured, b"\0\x05Hello");
+    }
+
+    #[test]
+    fn capture_only_written_value() {
+        let mut buffer = Vec::new();
+        write_into(&mut buffer, Plain("Hello, ")).unwrap();
+        let (captured, ()) =
+            write_into_capturing(&mut buffer, (Plain("Sailor"), Plain("!"))).unwrap();
+        assert_eq!(&captured, b"Sailor!");
+        assert_eq!(&buffer, b"Hello, Sailor!");
+    }
+}
@@ -0,0 +1,213 @@
+use super::{write_into, BigEndian, Plain, WriteInto};
+use std::collections::BTreeMap;
+use std::io;
+
+/// Used to write CBOR data item heads (major type and argument).
+///
+/// Arguments below 24 are stored in the initial byte, larger ones follow it as big endian
+/// [`u8`], [`u16`], [`u32`] or [`u64`]. Writing a major type larger than 7 fails with
+/// [`io::ErrorKind::InvalidInput`].
+///
+/// # Example
+///
+/// ```
+/// use write_into::{CborHead, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, CborHead { major: 3, value: 5 }).unwrap();
+/// assert_eq!(written, 1);
+/// assert_eq!(&buffer, &[0x65]);
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+pub struct CborHead {
+    /// Major type (e.g. `0` for unsigned integers or `4` for arrays).
+    pub major: u8,
+    /// Argument (e.g. the value of an integer or the length of an array).
+    pub value: u64,
+}
+
+/// Returns how many bytes was written.
+impl WriteInto for CborHead {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        if self.major > 7 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "major type does not fit into 3 bits",
+            ));
+        }
+
+        let major = self.major << 5;
+        if self.value < 24 {
+            write_into(sink, Plain(&(major | self.value as u8)))?;
+            Ok(1)
+        } else if let Ok(value) = u8::try_from(self.value) {
+            write_into(sink, Plain(&[major | 24, value]))?;
+            Ok(2)
+        } else if let Ok(value) = u16::try_from(self.value) {
+            write_into(sink, Plain(&(major | 25)))?;
+            write_into(sink, BigEndian(value))?;
+            Ok(3)
+        } else if let Ok(value) = u32::try_from(self.value) {
+            write_into(sink, Plain(&(major | 26)))?;
+            write_into(sink, BigEndian(value))?;
+            Ok(5)
+        } else {
+            write_into(sink, Plain(&(major | 27)))?;
+            write_into(sink, BigEndian(self.value))?;
+            Ok(9)
+        }
+    }
+}
+
+/// Returns how many bytes was written.
+impl WriteInto for &CborHead {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(
+            sink,
+            CborHead {
+                major: self.major,
+                value: self.value,
+            },
+        )
+    }
+}
+
+/// Used to write CBOR maps.
+///
+/// The map head holding the number of entries is followed by the entries in order of their keys.
+/// Keys and values are expected to be written as CBOR data items, e.g. with [`CborHead`].
+///
+/// # Example
+///
+/// ```
+/// use std::collections::BTreeMap;
+/// use write_into::{CborHead, CborMap, write_into};
+///
+/// let map = BTreeMap::from([(CborHead { major: 0, value: 1 }, CborHead { major: 0, value: 2 })]);
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, CborMap(map)).unwrap();
+/// assert_eq!(written, 1);
+/// assert_eq!(&buffer, &[0xA1, 0x01, 0x02]);
+/// ```
+pub struct CborMap<K, V>(pub BTreeMap<K, V>)
+where
+    K: WriteInto,
+    V: WriteInto;
+
+/// Returns how many entries was written.
+impl<K, V> WriteInto for CborMap<K, V>
+where
+    K: WriteInto,
+    V: WriteInto,
+{
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let written = self.0.len();
+        write_map_head(sink, written)?;
+        for (key, value) in self.0 {
+            write_into(sink, key)?;
+            write_into(sink, value)?;
+        }
+
+        Ok(written)
+    }
+}
+
+/// Returns how many entries was written.
+impl<'a, K, V> WriteInto for &'a CborMap<K, V>
+where
+    K: WriteInto,
+    V: WriteInto,
+    &'a K: WriteInto,
+    &'a V: WriteInto,
+{
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let written = self.0.len();
+        write_map_head(sink, written)?;
+        for (key, value) in &self.0 {
+            write_into(sink, key)?;
+            write_into(sink, value)?;
+        }
+
+        Ok(written)
+    }
+}
+
+fn write_map_head(sink: &mut impl io::Write, entries: usize) -> io::Result<()> {
+    write_into(
+        sink,
+        CborHead {
+            major: 5,
+            value: entries as u64,
+        },
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use super::*;
+    use test_case::test_case;
+    use validators::vec;
+
+    mod validators {
+        pub fn vec(expected: &[u8]) -> impl FnOnce(Vec<u8>) {
+            let expected = expected.to_vec();
+            move |actual| assert_eq!(&expected, &actual)
+        }
+    }
+
+    #[test_case( 0,                23 => using vec(&[ 0x17                                                 ]); "when inline" )]
+    #[test_case( 0,                24 => using vec(&[ 0x18, 0x18                                           ]); "when u8    " )]
+    #[test_case( 1,               500 => using vec(&[ 0x39, 0x01, 0xF4                                     ]); "when u16   " )]
+    #[test_case( 2,           100_000 => using vec(&[ 0x5A, 0x00, 0x01, 0x86, 0xA0                         ]); "when u32   " )]
+    #[test_case( 4, 1_000_000_000_000 => using vec(&[ 0x9B, 0x00, 0x00, 0x00, 0xE8, 0xD4, 0xA5, 0x10, 0x00 ]); "when u64   " )]
+    fn write_cbor_head(major: u8, value: u64) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, CborHead { major, value }).unwrap();
+        assert_eq!(written, buffer.len());
+        buffer
+    }
+
+    #[test]
+    fn write_invalid_major() {
+        let mut buffer = Vec::new();
+        let error = write_into(&mut buffer, CborHead { major: 8, value: 0 }).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn write_cbor_map() {
+        let uint = |value| CborHead { major: 0, value };
+        let map = CborMap(BTreeMap::from([
+            (uint(24), uint(1000)),
+            (uint(1), uint(2)),
+            (uint(3), uint(4)),
+        ]));
+
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, &map).unwrap();
+        assert_eq!(written, 3);
+        assert_eq!(
+            &buffer,
+            &[0xA3, 0x01, 0x02, 0x03, 0x04, 0x18, 0x18, 0x19, 0x03, 0xE8]
+        );
+    }
+
+    #[test]
+    fn write_empty_cbor_map() {
+        let mut buffer = Vec::new();
+        let written =
+            write_into(&mut buffer, CborMap(BTreeMap::<CborHead, CborHead>::new())).unwrap();
+        assert_eq!(written, 0);
+        assert_eq!(&buffer, &[0xA0]);
+    }
+}
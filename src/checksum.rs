@@ -0,0 +1,217 @@
+use super::{write_into, LittleEndian, Plain, WriteInto};
+use std::io;
+
+/// Used to write byte slices followed by their CRC-16 _(MODBUS)_ in little endian byte order.
+///
+/// # Example
+///
+/// ```
+/// use write_into::{Crc16Modbus, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, Crc16Modbus(&[0x01, 0x03, 0x00, 0x00, 0x00, 0x0A])).unwrap();
+/// assert_eq!(written, 8);
+/// assert_eq!(&buffer, &[0x01, 0x03, 0x00, 0x00, 0x00, 0x0A, 0xC5, 0xCD]);
+/// ```
+pub struct Crc16Modbus<'a>(pub &'a [u8]);
+
+/// Returns how many bytes was written (including the checksum).
+impl WriteInto for Crc16Modbus<'_> {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, Plain(self.0))?;
+        write_into(sink, LittleEndian(crc16_modbus(self.0)))?;
+        Ok(self.0.len() + 2)
+    }
+}
+
+/// Returns how many bytes was written (including the checksum).
+impl WriteInto for &Crc16Modbus<'_> {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, Crc16Modbus(self.0))
+    }
+}
+
+fn crc16_modbus(bytes: &[u8]) -> u16 {
+    let mut crc = 0xFFFFu16;
+    for &byte in bytes {
+        crc ^= byte as u16;
+        for _ in 0..8 {
+            let carry = crc & 1 != 0;
+            crc >>= 1;
+            if carry {
+                crc ^= 0xA001;
+            }
+        }
+    }
+
+    crc
+}
+
+/// Used to write values followed by the Fletcher-16 check bytes of their representation.
+///
+/// The check bytes are chosen so that the Fletcher-16 checksum of the whole written message is
+/// zero. The representation is not buffered, its checksum is computed while it is written.
+///
+/// # Example
+///
+/// ```
+/// use write_into::{Fletcher16Checksummed, Plain, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let ((), checksum) = write_into(&mut buffer, Fletcher16Checksummed(Plain("abcde"))).unwrap();
+/// assert_eq!(checksum, 0xC8F0);
+/// assert_eq!(&buffer, b"abcde\x46\xC8");
+/// ```
+pub struct Fletcher16Checksummed<T>(pub T)
+where
+    T: WriteInto;
+
+/// Returns the output of writing `T` and the Fletcher-16 checksum of its representation.
+impl<T> WriteInto for Fletcher16Checksummed<T>
+where
+    T: WriteInto,
+{
+    type Output = (T::Output, u16);
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let mut sink = Fletcher16Sink {
+            sink,
+            sum1: 0,
+            sum2: 0,
+        };
+
+        let output = write_into(&mut sink, self.0)?;
+        let Fletcher16Sink { sink, sum1, sum2 } = sink;
+        let check1 = 255 - (sum1 + sum2) % 255;
+        let check2 = 255 - (sum1 + check1) % 255;
+        write_into(sink, Plain(&[check1 as u8, check2 as u8]))?;
+        Ok((output, (sum2 << 8 | sum1) as u16))
+    }
+}
+
+/// Returns the output of writing `T` and the Fletcher-16 checksum of its representation.
+impl<T> WriteInto for &Fletcher16Checksummed<T>
+where
+    T: Copy + WriteInto,
+{
+    type Output = (T::Output, u16);
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, Fletcher16Checksummed(self.0))
+    }
+}
+
+struct Fletcher16Sink<W> {
+    sink: W,
+    sum1: u32,
+    sum2: u32,
+}
+
+impl<W> io::Write for Fletcher16Sink<W>
+where
+    W: io::Write,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.sink.write(buf)?;
+        for &byte in &buf[..written] {
+            self.sum1 = (self.sum1 + byte as u32) % 255;
+            self.sum2 = (self.sum2 + self.sum1) % 255;
+        }
+
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.sink.flush()
+    }
+}
+
+/// Running CRC-32 _(ISO-HDLC, as used by PNG and gzip)_.
+pub(crate) struct Crc32(u32);
+
+impl Crc32 {
+    const TABLE: [u32; 256] = {
+        let mut table = [0u32; 256];
+        let mut i = 0;
+        while i < 256 {
+            let mut crc = i as u32;
+            let mut bit = 0;
+            while bit < 8 {
+                crc = if crc & 1 != 0 {
+                    crc >> 1 ^ 0xEDB88320
+                } else {
+                    crc >> 1
+                };
+                bit += 1;
+            }
+
+            table[i] = crc;
+            i += 1;
+        }
+
+        table
+    };
+
+    pub(crate) fn new() -> Self {
+        Self(0xFFFFFFFF)
+    }
+
+    pub(crate) fn update(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = Self::TABLE[((self.0 ^ byte as u32) & 0xFF) as usize] ^ self.0 >> 8;
+        }
+    }
+
+    pub(crate) fn finish(&self) -> u32 {
+        !self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use super::*;
+    use test_case::test_case;
+
+    #[test]
+    fn crc16_modbus_check_value() {
+        assert_eq!(crc16_modbus(b"123456789"), 0x4B37);
+    }
+
+    #[test]
+    fn write_modbus_frame() {
+        let frame = [0x11, 0x03, 0x00, 0x6B, 0x00, 0x03];
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, Crc16Modbus(&frame)).unwrap();
+        assert_eq!(written, 8);
+        assert_eq!(&buffer, &[0x11, 0x03, 0x00, 0x6B, 0x00, 0x03, 0x76, 0x87]);
+    }
+
+    #[test]
+    fn crc32_check_value() {
+        let mut crc = Crc32::new();
+        crc.update(b"1234");
+        crc.update(b"56789");
+        assert_eq!(crc.finish(), 0xCBF43926);
+    }
+
+    #[test_case("abcde"    => 0xC8F0; "when abcde"   )]
+    #[test_case("abcdef"   => 0x2057; "when abcdef"  )]
+    #[test_case("abcdefgh" => 0x0627; "when abcdefgh")]
+    fn fletcher16_checksum(text: &str) -> u16 {
+        let mut buffer = Vec::new();
+        let ((), checksum) = write_into(&mut buffer, Fletcher16Checksummed(Plain(text))).unwrap();
+        let mut sink = Fletcher16Sink {
+            sink: io::sink(),
+            sum1: 0,
+            sum2: 0,
+        };
+        write_into(&mut sink, Plain(&buffer[..])).unwrap();
+        assert_eq!((sink.sum1, sink.sum2), (0, 0));
+        checksum
+    }
+}
@@ -0,0 +1,131 @@
+use super::{write_into, Plain, WriteInto};
+use std::io;
+
+/// Used to write byte slices in Consistent Overhead Byte Stuffing encoding.
+///
+/// The encoded bytes are followed by a zero delimiter, which is the only zero byte written.
+///
+/// # Example
+///
+/// ```
+/// use write_into::{Cobs, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, Cobs(&[0x11, 0x22, 0x00, 0x33])).unwrap();
+/// assert_eq!(written, 6);
+/// assert_eq!(&buffer, &[0x03, 0x11, 0x22, 0x02, 0x33, 0x00]);
+/// ```
+pub struct Cobs<'a>(pub &'a [u8]);
+
+/// Returns how many bytes was written (including the delimiter).
+impl WriteInto for Cobs<'_> {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let mut written = 0;
+        let mut segments = self.0.split(|&byte| byte == 0).peekable();
+        while let Some(segment) = segments.next() {
+            if segment.is_empty() {
+                write_into(sink, Plain(&1u8))?;
+                written += 1;
+                continue;
+            }
+
+            for chunk in segment.chunks(254) {
+                write_into(sink, Plain(&(chunk.len() as u8 + 1)))?;
+                write_into(sink, Plain(chunk))?;
+                written += chunk.len() + 1;
+            }
+
+            // A full chunk does not imply a trailing zero, so the zero which ends the segment
+            // needs its own empty chunk.
+            if segment.len() % 254 == 0 && segments.peek().is_some() {
+                write_into(sink, Plain(&1u8))?;
+                written += 1;
+            }
+        }
+
+        write_into(sink, Plain(&0u8))?;
+        Ok(written + 1)
+    }
+}
+
+/// Returns how many bytes was written (including the delimiter).
+impl WriteInto for &Cobs<'_> {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, Cobs(self.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use test_case::test_case;
+    use validators::vec;
+
+    mod validators {
+        pub fn vec(expected: &[u8]) -> impl FnOnce(Vec<u8>) {
+            let expected = expected.to_vec();
+            move |actual| assert_eq!(&expected, &actual)
+        }
+    }
+
+    fn write_cobs(data: &[u8]) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, Cobs(data)).unwrap();
+        assert_eq!(written, buffer.len());
+        buffer
+    }
+
+    fn range(start: u8, end: u8) -> Vec<u8> {
+        (start..=end).collect()
+    }
+
+    #[test_case(&[                        ] => using vec(&[ 0x01, 0x00                         ]); "when empty"              )]
+    #[test_case(&[ 0x00                   ] => using vec(&[ 0x01, 0x01, 0x00                   ]); "when zero"               )]
+    #[test_case(&[ 0x00, 0x00             ] => using vec(&[ 0x01, 0x01, 0x01, 0x00             ]); "when two zeros"          )]
+    #[test_case(&[ 0x00, 0x11, 0x00       ] => using vec(&[ 0x01, 0x02, 0x11, 0x01, 0x00       ]); "when zeros around"       )]
+    #[test_case(&[ 0x11, 0x22, 0x00, 0x33 ] => using vec(&[ 0x03, 0x11, 0x22, 0x02, 0x33, 0x00 ]); "when zero in the middle" )]
+    #[test_case(&[ 0x11, 0x22, 0x33, 0x44 ] => using vec(&[ 0x05, 0x11, 0x22, 0x33, 0x44, 0x00 ]); "when no zeros"           )]
+    #[test_case(&[ 0x11, 0x00, 0x00, 0x00 ] => using vec(&[ 0x02, 0x11, 0x01, 0x01, 0x01, 0x00 ]); "when trailing zeros"     )]
+    fn write_short(data: &[u8]) -> Vec<u8> {
+        write_cobs(data)
+    }
+
+    #[test]
+    fn write_254_non_zero_bytes() {
+        let data = range(0x01, 0xFE);
+        let expected = [&[0xFF][..], &data, &[0x00]].concat();
+        assert_eq!(write_cobs(&data), expected);
+    }
+
+    #[test]
+    fn write_zero_then_254_non_zero_bytes() {
+        let data = [&[0x00][..], &range(0x01, 0xFE)].concat();
+        let expected = [&[0x01, 0xFF][..], &range(0x01, 0xFE), &[0x00]].concat();
+        assert_eq!(write_cobs(&data), expected);
+    }
+
+    #[test]
+    fn write_255_non_zero_bytes() {
+        let data = range(0x01, 0xFF);
+        let expected = [&[0xFF][..], &range(0x01, 0xFE), &[0x02, 0xFF, 0x00]].concat();
+        assert_eq!(write_cobs(&data), expected);
+    }
+
+    #[test]
+    fn write_254_non_zero_bytes_then_zero() {
+        let data = [&range(0x02, 0xFF)[..], &[0x00]].concat();
+        let expected = [&[0xFF][..], &range(0x02, 0xFF), &[0x01, 0x01, 0x00]].concat();
+        assert_eq!(write_cobs(&data), expected);
+    }
+
+    #[test]
+    fn write_253_non_zero_bytes_then_zero_then_one() {
+        let data = [&range(0x03, 0xFF)[..], &[0x00, 0x01]].concat();
+        let expected = [&[0xFE][..], &range(0x03, 0xFF), &[0x02, 0x01, 0x00]].concat();
+        assert_eq!(write_cobs(&data), expected);
+    }
+}
@@ -0,0 +1,75 @@
+use super::io;
+
+/// I/O sink adapter that counts bytes written into the underlying I/O sink.
+///
+/// # Example
+///
+#[cfg_attr(feature = "std", doc = "```")]
+#[cfg_attr(not(feature = "std"), doc = "```ignore")]
+/// use std::io;
+/// use write_into::{BigEndian, CountingWriter, Sequence, write_into};
+///
+/// let mut sink = CountingWriter::new(io::sink());
+/// write_into(&mut sink, Sequence((0..100u16).map(BigEndian))).unwrap();
+/// assert_eq!(sink.count(), 200);
+/// ```
+pub struct CountingWriter<W>
+where
+    W: io::Write,
+{
+    sink: W,
+    count: u64,
+}
+
+impl<W> CountingWriter<W>
+where
+    W: io::Write,
+{
+    /// Wraps the I/O sink.
+    pub fn new(sink: W) -> Self {
+        Self { sink, count: 0 }
+    }
+
+    /// Returns how many bytes was written.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the I/O sink.
+    pub fn into_inner(self) -> W {
+        self.sink
+    }
+}
+
+impl<W> io::Write for CountingWriter<W>
+where
+    W: io::Write,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.sink.write(buf)?;
+        self.count += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.sink.flush()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::super::*;
+
+    #[test]
+    fn count_matches_buffer() {
+        let mut buffer = Vec::new();
+        let mut sink = CountingWriter::new(&mut buffer);
+        write_into(&mut sink, BigEndian(0xCAFEBABEu32)).unwrap();
+        write_into(&mut sink, Uleb128(300u32)).unwrap();
+        write_into(&mut sink, Plain("Hello")).unwrap();
+        write_into(&mut sink, Sized(Uleb128, Plain("Sailor"))).unwrap();
+        let count = sink.count();
+        assert_eq!(count, 18);
+        assert_eq!(count, buffer.len() as u64);
+    }
+}
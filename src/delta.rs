@@ -0,0 +1,64 @@
+use super::{write_into, WriteInto, ZigzagLeb128};
+use std::io;
+
+/// Used to write series of signed numbers as zigzag-encoded deltas in LEB-128 format
+/// _(unsigned)_.
+///
+/// The first number is written as is, every next one as its difference from the previous one.
+/// Differences wrap on overflow, so adding them back with wrapping arithmetic restores the series.
+///
+/// # Example
+///
+/// ```
+/// use write_into::{ZigZagDeltaSequence, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, ZigZagDeltaSequence(&[100, 99, 101])).unwrap();
+/// assert_eq!(written, 3);
+/// assert_eq!(&buffer, &[0xC8, 0x01, 0x01, 0x04]);
+/// ```
+pub struct ZigZagDeltaSequence<'a>(pub &'a [i32]);
+
+/// Returns how many items was written.
+impl WriteInto for ZigZagDeltaSequence<'_> {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let mut previous = 0i32;
+        for &value in self.0 {
+            write_into(sink, ZigzagLeb128(value.wrapping_sub(previous)))?;
+            previous = value;
+        }
+
+        Ok(self.0.len())
+    }
+}
+
+/// Returns how many items was written.
+impl WriteInto for &ZigZagDeltaSequence<'_> {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, ZigZagDeltaSequence(self.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+
+    #[test]
+    fn write_deltas() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, ZigZagDeltaSequence(&[10, 5, 20])).unwrap();
+        assert_eq!(written, 3);
+        assert_eq!(&buffer, &[0x14, 0x09, 0x1E]);
+    }
+
+    #[test]
+    fn write_wrapping_deltas() {
+        let mut buffer = Vec::new();
+        write_into(&mut buffer, ZigZagDeltaSequence(&[i32::MIN, i32::MAX])).unwrap();
+        assert_eq!(&buffer, &[0xFF, 0xFF, 0xFF, 0xFF, 0x0F, 0x01]);
+    }
+}
@@ -1,49 +1,101 @@
-use super::{write_into, WriteInto};
-use std::io;
-use std::mem::size_of;
+use super::{io, write_into, WriteInto};
+use core::mem::size_of;
 
 /// Used to write values in big endian byte order.
 ///
+/// References to slices and arrays of primitives are written element by element.
+///
 /// # Example
 ///
-/// ```
+#[cfg_attr(feature = "alloc", doc = "```")]
+#[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
 /// use write_into::{BigEndian, write_into};
 ///
 /// let mut buffer = Vec::new();
-/// write_into(&mut buffer, BigEndian(0xCAFEBABEu32)).unwrap();
+/// let written = write_into(&mut buffer, BigEndian(0xCAFEBABEu32)).unwrap();
+/// assert_eq!(written, 4);
 /// assert_eq!(&buffer, &[0xCA, 0xFE, 0xBA, 0xBE]);
 /// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct BigEndian<T>(pub T);
 
 /// Used to write values in little endian byte order.
 ///
+/// References to slices and arrays of primitives are written element by element.
+///
 /// # Example
 ///
-/// ```
+#[cfg_attr(feature = "alloc", doc = "```")]
+#[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
 /// use write_into::{LittleEndian, write_into};
 ///
 /// let mut buffer = Vec::new();
-/// write_into(&mut buffer, LittleEndian(0xCAFEBABEu32)).unwrap();
+/// let written = write_into(&mut buffer, LittleEndian(0xCAFEBABEu32)).unwrap();
+/// assert_eq!(written, 4);
 /// assert_eq!(&buffer, &[0xBE, 0xBA, 0xFE, 0xCA]);
 /// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct LittleEndian<T>(pub T);
 
 macro_rules! impl_write_into {
     ($($wrapper:ident => { $($primitive:ident)* } ),*,) => {
         $(
             $(
+                /// Returns how many bytes was written.
                 impl WriteInto for $wrapper<$primitive> {
-                    type Output = ();
+                    type Output = usize;
 
                     fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
                         let bytes = convertion!($wrapper, self.0);
                         sink.write_all(&bytes)?;
-                        Ok(())
+                        Ok(bytes.len())
                     }
                 }
 
+                /// Returns how many bytes was written.
                 impl WriteInto for &$wrapper<$primitive> {
-                    type Output = ();
+                    type Output = usize;
+
+                    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+                        write_into(sink, $wrapper(self.0))
+                    }
+                }
+
+                /// Writes each element in the byte order and returns how many bytes was written.
+                impl WriteInto for $wrapper<&[$primitive]> {
+                    type Output = usize;
+
+                    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+                        let mut written = 0;
+                        for &element in self.0 {
+                            written += write_into(sink, $wrapper(element))?;
+                        }
+
+                        Ok(written)
+                    }
+                }
+
+                /// Writes each element in the byte order and returns how many bytes was written.
+                impl WriteInto for &$wrapper<&[$primitive]> {
+                    type Output = usize;
+
+                    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+                        write_into(sink, $wrapper(self.0))
+                    }
+                }
+
+                /// Writes each element in the byte order and returns how many bytes was written.
+                impl<const N: usize> WriteInto for $wrapper<&[$primitive; N]> {
+                    type Output = usize;
+
+                    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+                        write_into(sink, $wrapper(&self.0[..]))
+                    }
+                }
+
+                /// Writes each element in the byte order and returns how many bytes was written.
+                impl<const N: usize> WriteInto for &$wrapper<&[$primitive; N]> {
+                    type Output = usize;
 
                     fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
                         write_into(sink, $wrapper(self.0))
@@ -76,6 +128,105 @@ impl_write_into! {
     },
 }
 
+/// Used to write values in byte order chosen by a const parameter.
+///
+/// `Endian<true, T>` writes like [`BigEndian`] and `Endian<false, T>` writes like
+/// [`LittleEndian`], which allows code generic over the byte order.
+///
+/// # Example
+///
+#[cfg_attr(feature = "alloc", doc = "```")]
+#[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+/// use write_into::{Endian, write_into};
+///
+/// fn emit<const BIG: bool>(value: u16) -> Vec<u8> {
+///     let mut buffer = Vec::new();
+///     write_into(&mut buffer, Endian::<BIG, _>(value)).unwrap();
+///     buffer
+/// }
+///
+/// assert_eq!(emit::<true>(0xCAFE), &[0xCA, 0xFE]);
+/// assert_eq!(emit::<false>(0xCAFE), &[0xFE, 0xCA]);
+/// ```
+#[derive(Clone, Copy)]
+pub struct Endian<const BIG: bool, T>(pub T);
+
+macro_rules! impl_write_into_endian {
+    ($($primitive:ident)*) => {
+        $(
+            /// Returns how many bytes was written.
+            impl<const BIG: bool> WriteInto for Endian<BIG, $primitive> {
+                type Output = usize;
+
+                fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+                    if BIG {
+                        write_into(sink, BigEndian(self.0))
+                    } else {
+                        write_into(sink, LittleEndian(self.0))
+                    }
+                }
+            }
+
+            /// Returns how many bytes was written.
+            impl<const BIG: bool> WriteInto for &Endian<BIG, $primitive> {
+                type Output = usize;
+
+                fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+                    write_into(sink, Endian::<BIG, _>(self.0))
+                }
+            }
+        )*
+    };
+}
+
+impl_write_into_endian! {
+    i8 i16 i32 i64 i128 isize
+    u8 u16 u32 u64 u128 usize
+    bool char f32 f64
+}
+
+/// Used to write slices of primitives in little endian byte order regardless of the host.
+///
+/// Intended as the canonical wrapper for data stored little endian on disk. Writes the same as
+/// [`LittleEndian`] over a slice.
+///
+/// # Example
+///
+#[cfg_attr(feature = "alloc", doc = "```")]
+#[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+/// use write_into::{StoredLe, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, StoredLe(&[0xAABBu16, 0xCCDD])).unwrap();
+/// assert_eq!(written, 4);
+/// assert_eq!(&buffer, &[0xBB, 0xAA, 0xDD, 0xCC]);
+/// ```
+pub struct StoredLe<'a, T>(pub &'a [T]);
+
+/// Returns how many bytes was written.
+impl<'a, T> WriteInto for StoredLe<'a, T>
+where
+    LittleEndian<&'a [T]>: WriteInto,
+{
+    type Output = <LittleEndian<&'a [T]> as WriteInto>::Output;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, LittleEndian(self.0))
+    }
+}
+
+/// Returns how many bytes was written.
+impl<'a, T> WriteInto for &StoredLe<'a, T>
+where
+    LittleEndian<&'a [T]>: WriteInto,
+{
+    type Output = <LittleEndian<&'a [T]> as WriteInto>::Output;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, StoredLe(self.0))
+    }
+}
+
 trait EndiannessExts {
     type Repr;
     fn to_be_bytes(self) -> Self::Repr;
@@ -105,7 +256,7 @@ impl_endianness_exts! {
     bool => u8,
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use super::*;
 
@@ -118,4 +269,104 @@ mod tests {
     fn char_le() {
         assert_eq!('\x7F'.to_le_bytes(), 0x7Fu32.to_le_bytes());
     }
+
+    #[test]
+    fn written_be() {
+        let mut buffer = Vec::new();
+        assert_eq!(write_into(&mut buffer, BigEndian(0u64)).unwrap(), 8);
+        assert_eq!(write_into(&mut buffer, BigEndian('\x7F')).unwrap(), 4);
+        assert_eq!(write_into(&mut buffer, &BigEndian(true)).unwrap(), 1);
+        assert_eq!(buffer.len(), 13);
+    }
+
+    #[test]
+    fn written_le() {
+        let mut buffer = Vec::new();
+        assert_eq!(write_into(&mut buffer, LittleEndian(0u64)).unwrap(), 8);
+        assert_eq!(write_into(&mut buffer, LittleEndian(0.0f32)).unwrap(), 4);
+        assert_eq!(write_into(&mut buffer, &LittleEndian(0u128)).unwrap(), 16);
+        assert_eq!(buffer.len(), 28);
+    }
+
+    #[test]
+    fn slice_be() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, BigEndian(&[0xAABBu16, 0xCCDDu16][..])).unwrap();
+        assert_eq!(written, 4);
+        assert_eq!(&buffer, &[0xAA, 0xBB, 0xCC, 0xDD]);
+    }
+
+    #[test]
+    fn slice_le() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, &LittleEndian(&[1.0f32, -2.0][..])).unwrap();
+        assert_eq!(written, 8);
+        assert_eq!(&buffer, &[0x00, 0x00, 0x80, 0x3F, 0x00, 0x00, 0x00, 0xC0]);
+    }
+
+    #[test]
+    fn array_be() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, BigEndian(&[0x0102u16, 0x0304, 0x0506])).unwrap();
+        assert_eq!(written, 6);
+        assert_eq!(&buffer, &[0x01, 0x02, 0x03, 0x04, 0x05, 0x06]);
+    }
+
+    #[test]
+    fn array_le() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, LittleEndian(&[0x01020304u32; 2])).unwrap();
+        assert_eq!(written, 8);
+        assert_eq!(&buffer, &[0x04, 0x03, 0x02, 0x01, 0x04, 0x03, 0x02, 0x01]);
+    }
+
+    #[test]
+    fn empty_slice() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, BigEndian(&[] as &[u64])).unwrap();
+        assert_eq!(written, 0);
+        assert!(buffer.is_empty());
+    }
+
+    fn written_endian<const BIG: bool>() -> Vec<u8> {
+        let mut buffer = Vec::new();
+        assert_eq!(
+            write_into(&mut buffer, Endian::<BIG, _>(0xCAFEBABEu32)).unwrap(),
+            4
+        );
+        assert_eq!(
+            write_into(&mut buffer, &Endian::<BIG, _>('\x7F')).unwrap(),
+            4
+        );
+        buffer
+    }
+
+    #[test]
+    fn endian_matches_wrappers() {
+        let mut be = Vec::new();
+        write_into(&mut be, BigEndian(0xCAFEBABEu32)).unwrap();
+        write_into(&mut be, BigEndian('\x7F')).unwrap();
+        let mut le = Vec::new();
+        write_into(&mut le, LittleEndian(0xCAFEBABEu32)).unwrap();
+        write_into(&mut le, LittleEndian('\x7F')).unwrap();
+        assert_eq!(written_endian::<true>(), be);
+        assert_eq!(written_endian::<false>(), le);
+    }
+
+    #[test]
+    fn stored_le_regardless_of_host() {
+        let values = [0x0102030405060708u64, u64::MAX - 1];
+        let expected: Vec<u8> = values
+            .iter()
+            .flat_map(|value| value.to_le_bytes())
+            .collect();
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, &StoredLe(&values)).unwrap();
+        assert_eq!(written, 16);
+        assert_eq!(buffer, expected);
+        assert_eq!(
+            &buffer[..8],
+            &[0x08, 0x07, 0x06, 0x05, 0x04, 0x03, 0x02, 0x01]
+        );
+    }
 }
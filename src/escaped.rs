@@ -0,0 +1,106 @@
+use super::{write_into, Plain, WriteInto};
+use std::io;
+
+/// Used to write byte slices with occurrences of the delimiter and the escape bytes prefixed by
+/// the escape byte.
+///
+/// # Example
+///
+/// ```
+/// use write_into::{Escaped, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, Escaped {
+///     escape: 0x01,
+///     delimiter: 0x00,
+///     data: &[0xAA, 0x00, 0xBB],
+/// }).unwrap();
+/// assert_eq!(written, 4);
+/// assert_eq!(&buffer, &[0xAA, 0x01, 0x00, 0xBB]);
+/// ```
+pub struct Escaped<'a> {
+    /// Byte prepended to the escaped bytes.
+    pub escape: u8,
+    /// Byte that must not appear in the representation unescaped.
+    pub delimiter: u8,
+    /// Bytes to write.
+    pub data: &'a [u8],
+}
+
+/// Returns how many bytes was written.
+impl WriteInto for Escaped<'_> {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let mut written = 0;
+        let mut rest = self.data;
+        while let Some(position) = rest
+            .iter()
+            .position(|&byte| byte == self.escape || byte == self.delimiter)
+        {
+            write_into(sink, Plain(&rest[..position]))?;
+            write_into(sink, Plain(&[self.escape, rest[position]]))?;
+            written += position + 2;
+            rest = &rest[position + 1..];
+        }
+
+        write_into(sink, Plain(rest))?;
+        Ok(written + rest.len())
+    }
+}
+
+/// Returns how many bytes was written.
+impl WriteInto for &Escaped<'_> {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(
+            sink,
+            Escaped {
+                escape: self.escape,
+                delimiter: self.delimiter,
+                data: self.data,
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+
+    #[test]
+    fn write_delimiter_and_escape() {
+        let mut buffer = Vec::new();
+        let written = write_into(
+            &mut buffer,
+            Escaped {
+                escape: 0x01,
+                delimiter: 0x00,
+                data: &[0x00, 0x7F, 0x01, 0x01, 0x80, 0x00],
+            },
+        )
+        .unwrap();
+        assert_eq!(written, 10);
+        assert_eq!(
+            &buffer,
+            &[0x01, 0x00, 0x7F, 0x01, 0x01, 0x01, 0x01, 0x80, 0x01, 0x00]
+        );
+    }
+
+    #[test]
+    fn write_nothing_to_escape() {
+        let mut buffer = Vec::new();
+        let written = write_into(
+            &mut buffer,
+            Escaped {
+                escape: 0x01,
+                delimiter: 0x00,
+                data: b"plain",
+            },
+        )
+        .unwrap();
+        assert_eq!(written, 5);
+        assert_eq!(&buffer, b"plain");
+    }
+}
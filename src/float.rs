@@ -0,0 +1,164 @@
+use super::{write_into, BigEndian, LittleEndian, WriteInto};
+use std::io;
+
+/// Used to write floating point numbers with subnormal values flushed to zero.
+///
+/// The sign of flushed values is preserved. Wrap the number into [`BigEndian`] or
+/// [`LittleEndian`] to choose the byte order.
+///
+/// # Example
+///
+/// ```
+/// use write_into::{BigEndian, FlushDenormal, write_into};
+///
+/// let mut buffer = Vec::new();
+/// write_into(&mut buffer, FlushDenormal(BigEndian(-f64::MIN_POSITIVE / 2.0))).unwrap();
+/// assert_eq!(&buffer, &[0x80, 0, 0, 0, 0, 0, 0, 0]);
+/// ```
+pub struct FlushDenormal<E>(pub E);
+
+macro_rules! impl_flush_denormal {
+    ($($wrapper:ident => { $($float:ident)* }),*,) => {
+        $(
+            $(
+                impl WriteInto for FlushDenormal<$wrapper<$float>> {
+                    type Output = ();
+
+                    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+                        let value = (self.0).0;
+                        let value = if value.is_subnormal() {
+                            (0.0 as $float).copysign(value)
+                        } else {
+                            value
+                        };
+
+                        write_into(sink, $wrapper(value))?;
+                        Ok(())
+                    }
+                }
+
+                impl WriteInto for &FlushDenormal<$wrapper<$float>> {
+                    type Output = ();
+
+                    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+                        write_into(sink, FlushDenormal($wrapper((self.0).0)))
+                    }
+                }
+            )*
+        )*
+    };
+}
+
+impl_flush_denormal! {
+    BigEndian => { f32 f64 },
+    LittleEndian => { f32 f64 },
+}
+
+/// Used to write slices of floating point numbers with NaNs canonicalized.
+///
+/// Every NaN is written as the quiet NaN with bit pattern `0x7FC0_0000` regardless of its sign
+/// and payload, which makes the output reproducible. Wrap the slice into [`BigEndian`] or
+/// [`LittleEndian`] to choose the byte order.
+///
+/// # Example
+///
+/// ```
+/// use write_into::{FloatArray, LittleEndian, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, FloatArray(LittleEndian(&[1.0, -f32::NAN][..]))).unwrap();
+/// assert_eq!(written, 8);
+/// assert_eq!(&buffer, &[0x00, 0x00, 0x80, 0x3F, 0x00, 0x00, 0xC0, 0x7F]);
+/// ```
+pub struct FloatArray<E>(pub E);
+
+const CANONICAL_NAN: f32 = f32::from_bits(0x7FC0_0000);
+
+macro_rules! impl_float_array {
+    ($($wrapper:ident)*) => {
+        $(
+            /// Returns how many bytes was written.
+            impl WriteInto for FloatArray<$wrapper<&[f32]>> {
+                type Output = usize;
+
+                fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+                    let mut written = 0;
+                    for &value in (self.0).0 {
+                        let value = if value.is_nan() { CANONICAL_NAN } else { value };
+                        written += write_into(sink, $wrapper(value))?;
+                    }
+
+                    Ok(written)
+                }
+            }
+
+            /// Returns how many bytes was written.
+            impl WriteInto for &FloatArray<$wrapper<&[f32]>> {
+                type Output = usize;
+
+                fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+                    write_into(sink, FloatArray($wrapper((self.0).0)))
+                }
+            }
+        )*
+    };
+}
+
+impl_float_array! {
+    BigEndian LittleEndian
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use test_case::test_case;
+    use validators::vec;
+
+    mod validators {
+        pub fn vec(expected: &[u8]) -> impl FnOnce(Vec<u8>) {
+            let expected = expected.to_vec();
+            move |actual| assert_eq!(&expected, &actual)
+        }
+    }
+
+    #[test_case(  f64::MIN_POSITIVE / 2.0 => using vec(&[ 0x00, 0x00, 0, 0, 0, 0, 0, 0 ]); "when positive subnormal" )]
+    #[test_case( -f64::MIN_POSITIVE / 2.0 => using vec(&[ 0x80, 0x00, 0, 0, 0, 0, 0, 0 ]); "when negative subnormal" )]
+    #[test_case(  f64::from_bits(1)       => using vec(&[ 0x00, 0x00, 0, 0, 0, 0, 0, 0 ]); "when smallest subnormal" )]
+    #[test_case(  f64::MIN_POSITIVE       => using vec(&[ 0x00, 0x10, 0, 0, 0, 0, 0, 0 ]); "when smallest normal"    )]
+    #[test_case(  1.5                     => using vec(&[ 0x3F, 0xF8, 0, 0, 0, 0, 0, 0 ]); "when normal"             )]
+    fn write_f64_be(value: f64) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        write_into(&mut buffer, FlushDenormal(BigEndian(value))).unwrap();
+        buffer
+    }
+
+    #[test]
+    fn write_f32_le() {
+        let mut buffer = Vec::new();
+        write_into(&mut buffer, FlushDenormal(LittleEndian(-f32::from_bits(1)))).unwrap();
+        assert_eq!(&buffer, &[0x00, 0x00, 0x00, 0x80]);
+    }
+
+    #[test]
+    fn write_float_array_with_nans() {
+        let quiet = f32::from_bits(0x7FC0_0001);
+        let signaling = f32::from_bits(0xFF80_0001);
+        let values = [0.5, quiet, -0.0, signaling, f32::INFINITY];
+
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, FloatArray(BigEndian(&values[..]))).unwrap();
+        assert_eq!(written, 20);
+        assert_eq!(
+            &buffer,
+            &[
+                0x3F, 0x00, 0x00, 0x00, 0x7F, 0xC0, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00, 0x7F, 0xC0,
+                0x00, 0x00, 0x7F, 0x80, 0x00, 0x00,
+            ]
+        );
+
+        let values = [0.5, f32::NAN, -0.0, -f32::NAN, f32::INFINITY];
+        let mut again = Vec::new();
+        write_into(&mut again, &FloatArray(BigEndian(&values[..]))).unwrap();
+        assert_eq!(again, buffer);
+    }
+}
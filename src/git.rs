@@ -0,0 +1,135 @@
+use super::{write_into, Plain, WriteInto};
+use std::io;
+
+/// Used to write Git tree entries (mode, name and binary SHA-1 of the object).
+///
+/// The fields are the mode (e.g. `100644` for a regular file), the name and the SHA-1. Writing a
+/// name containing a zero byte fails with [`io::ErrorKind::InvalidInput`].
+///
+/// # Example
+///
+/// ```
+/// use write_into::{GitTreeEntry, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, GitTreeEntry("40000", "src", [0xAB; 20])).unwrap();
+/// assert_eq!(written, 30);
+/// assert_eq!(&buffer[..10], b"40000 src\0");
+/// assert_eq!(&buffer[10..], &[0xAB; 20]);
+/// ```
+pub struct GitTreeEntry<'a>(pub &'a str, pub &'a str, pub [u8; 20]);
+
+/// Returns how many bytes was written.
+impl WriteInto for GitTreeEntry<'_> {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        if self.1.contains('\0') {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "name contains a zero byte",
+            ));
+        }
+
+        let header = format!("{} {}\0", self.0, self.1);
+        write_into(sink, Plain(&*header))?;
+        write_into(sink, Plain(&self.2))?;
+        Ok(header.len() + 20)
+    }
+}
+
+/// Returns how many bytes was written.
+impl WriteInto for &GitTreeEntry<'_> {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, GitTreeEntry(self.0, self.1, self.2))
+    }
+}
+
+/// Used to write headers of Git loose objects (kind and length of the content).
+///
+/// # Example
+///
+/// ```
+/// use write_into::{GitObjectHeader, Plain, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let content = "Hello, Sailor!";
+/// let written = write_into(&mut buffer, GitObjectHeader { kind: "blob", len: content.len() }).unwrap();
+/// write_into(&mut buffer, Plain(content)).unwrap();
+/// assert_eq!(written, 8);
+/// assert_eq!(&buffer, b"blob 14\0Hello, Sailor!");
+/// ```
+pub struct GitObjectHeader<'a> {
+    /// Kind of the object (`blob`, `tree`, `commit` or `tag`).
+    pub kind: &'a str,
+    /// Length of the content in bytes.
+    pub len: usize,
+}
+
+/// Returns how many bytes was written.
+impl WriteInto for GitObjectHeader<'_> {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let header = format!("{} {}\0", self.kind, self.len);
+        write_into(sink, Plain(&*header))?;
+        Ok(header.len())
+    }
+}
+
+/// Returns how many bytes was written.
+impl WriteInto for &GitObjectHeader<'_> {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(
+            sink,
+            GitObjectHeader {
+                kind: self.kind,
+                len: self.len,
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+
+    #[test]
+    fn write_blob_entry() {
+        let sha = [
+            0x3B, 0x18, 0xE5, 0x12, 0xDB, 0xA7, 0x9E, 0x4C, 0x83, 0x00, 0xDD, 0x08, 0xAE, 0xB3,
+            0x7F, 0x8E, 0x72, 0x8B, 0x8D, 0xAD,
+        ];
+
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, GitTreeEntry("100644", "file.txt", sha)).unwrap();
+        assert_eq!(written, 36);
+        assert_eq!(&buffer[..16], b"100644 file.txt\0");
+        assert_eq!(&buffer[16..], &sha);
+    }
+
+    #[test]
+    fn write_entry_with_zero_in_name() {
+        let mut buffer = Vec::new();
+        let error = write_into(&mut buffer, GitTreeEntry("100644", "a\0b", [0; 20])).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn write_blob_header() {
+        let mut buffer = Vec::new();
+        let header = GitObjectHeader {
+            kind: "blob",
+            len: 12,
+        };
+
+        let written = write_into(&mut buffer, &header).unwrap();
+        assert_eq!(written, 8);
+        assert_eq!(&buffer, b"blob 12\0");
+    }
+}
@@ -0,0 +1,92 @@
+use super::{write_into, Plain, WriteInto};
+use flate2::{Compression, GzBuilder};
+use std::io::{self, Write};
+
+/// Used to write byte slices as gzip members _(requires `flate2` feature)_.
+///
+/// The member consists of the header, the deflate compressed bytes and the footer with CRC-32
+/// and size of the uncompressed bytes. For reproducible output the header has zero modification
+/// time and no file name.
+///
+/// # Example
+///
+/// ```
+/// use std::io::Read;
+/// use flate2::read::GzDecoder;
+/// use write_into::{GzipMember, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, GzipMember(b"Hello, Sailor!")).unwrap();
+/// assert_eq!(written, buffer.len());
+/// assert_eq!(&buffer[..2], &[0x1F, 0x8B]);
+///
+/// let mut text = String::new();
+/// GzDecoder::new(&buffer[..]).read_to_string(&mut text).unwrap();
+/// assert_eq!(text, "Hello, Sailor!");
+/// ```
+pub struct GzipMember<'a>(pub &'a [u8]);
+
+/// Returns how many bytes was written.
+impl WriteInto for GzipMember<'_> {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let mut encoder = GzBuilder::new()
+            .mtime(0)
+            .write(Vec::new(), Compression::default());
+        encoder.write_all(self.0)?;
+        let compressed = encoder.finish()?;
+
+        write_into(sink, Plain(&compressed[..]))?;
+        Ok(compressed.len())
+    }
+}
+
+/// Returns how many bytes was written.
+impl WriteInto for &GzipMember<'_> {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, GzipMember(self.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use flate2::read::GzDecoder;
+    use std::io::Read;
+
+    #[test]
+    fn round_trip() {
+        let data = b"Hello, Sailor! ".repeat(16);
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, GzipMember(&data)).unwrap();
+        assert_eq!(written, buffer.len());
+        assert!(written < data.len());
+
+        let mut decompressed = Vec::new();
+        GzDecoder::new(&buffer[..])
+            .read_to_end(&mut decompressed)
+            .unwrap();
+        assert_eq!(decompressed, data);
+    }
+
+    #[test]
+    fn write_reproducible_header_and_footer() {
+        let mut first = Vec::new();
+        write_into(&mut first, GzipMember(b"abc")).unwrap();
+        let mut second = Vec::new();
+        write_into(&mut second, &GzipMember(b"abc")).unwrap();
+        assert_eq!(first, second);
+
+        // Magic, deflate method, no flags and zero modification time.
+        assert_eq!(
+            &first[..8],
+            &[0x1F, 0x8B, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00]
+        );
+        // CRC-32 and size of the uncompressed bytes.
+        let footer = &first[first.len() - 8..];
+        assert_eq!(footer, &[0xC2, 0x41, 0x24, 0x35, 0x03, 0x00, 0x00, 0x00]);
+    }
+}
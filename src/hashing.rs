@@ -0,0 +1,193 @@
+use super::{write_into, WriteInto};
+#[cfg(feature = "sha2")]
+use sha2::digest::Output;
+#[cfg(feature = "sha2")]
+use sha2::Digest;
+use std::io;
+
+/// I/O sink adapter that feeds all written bytes into a hasher _(requires `sha2` feature)_.
+///
+/// # Example
+///
+/// ```
+/// use sha2::Sha256;
+/// use write_into::{HashingSink, Plain, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let mut sink = HashingSink::<Sha256, _>::new(&mut buffer);
+/// write_into(&mut sink, Plain("abc")).unwrap();
+/// let (_, digest) = sink.finalize();
+/// assert_eq!(digest[..4], [0xBA, 0x78, 0x16, 0xBF]);
+/// assert_eq!(&buffer, b"abc");
+/// ```
+#[cfg(feature = "sha2")]
+pub struct HashingSink<H, W>
+where
+    H: Digest,
+    W: io::Write,
+{
+    hasher: H,
+    sink: W,
+}
+
+#[cfg(feature = "sha2")]
+impl<H, W> HashingSink<H, W>
+where
+    H: Digest,
+    W: io::Write,
+{
+    /// Wraps the I/O sink.
+    pub fn new(sink: W) -> Self {
+        Self {
+            hasher: H::new(),
+            sink,
+        }
+    }
+
+    /// Returns the I/O sink and the digest of all bytes written into it.
+    pub fn finalize(self) -> (W, Output<H>) {
+        (self.sink, self.hasher.finalize())
+    }
+}
+
+#[cfg(feature = "sha2")]
+impl<H, W> io::Write for HashingSink<H, W>
+where
+    H: Digest,
+    W: io::Write,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.sink.write(buf)?;
+        self.hasher.update(&buf[..written]);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.sink.flush()
+    }
+}
+
+/// Writes value into I/O sink and returns the digest of its representation _(requires `sha2`
+/// feature)_.
+///
+/// # Example
+///
+/// ```
+/// use sha2::Sha256;
+/// use write_into::{BigEndian, write_into_hashed};
+///
+/// let mut buffer = Vec::new();
+/// let (_, digest) = write_into_hashed::<Sha256, _>(&mut buffer, BigEndian(0x616263u32)).unwrap();
+/// assert_eq!(digest.len(), 32);
+/// assert_eq!(&buffer, b"\0abc");
+/// ```
+#[cfg(feature = "sha2")]
+pub fn write_into_hashed<H: Digest, T: WriteInto>(
+    sink: &mut impl io::Write,
+    value: T,
+) -> io::Result<(T::Output, Output<H>)> {
+    let mut sink = HashingSink::<H, _>::new(sink);
+    let output = write_into(&mut sink, value)?;
+    let (_, digest) = sink.finalize();
+    Ok((output, digest))
+}
+
+/// Writes value into I/O sink and returns the BLAKE3 hash of its representation _(requires
+/// `blake3` feature)_.
+///
+/// # Example
+///
+/// ```
+/// use write_into::{BigEndian, write_into_blake3};
+///
+/// let mut buffer = Vec::new();
+/// let (_, hash) = write_into_blake3(&mut buffer, BigEndian(0x616263u32)).unwrap();
+/// assert_eq!(hash, *blake3::hash(b"\0abc").as_bytes());
+/// ```
+#[cfg(feature = "blake3")]
+pub fn write_into_blake3<T: WriteInto>(
+    sink: &mut impl io::Write,
+    value: T,
+) -> io::Result<(T::Output, [u8; 32])> {
+    let mut sink = Blake3Sink {
+        hasher: blake3::Hasher::new(),
+        sink,
+    };
+
+    let output = write_into(&mut sink, value)?;
+    Ok((output, *sink.hasher.finalize().as_bytes()))
+}
+
+#[cfg(feature = "blake3")]
+struct Blake3Sink<W> {
+    hasher: blake3::Hasher,
+    sink: W,
+}
+
+#[cfg(feature = "blake3")]
+impl<W> io::Write for Blake3Sink<W>
+where
+    W: io::Write,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.sink.write(buf)?;
+        self.hasher.update(&buf[..written]);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.sink.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use super::*;
+    #[cfg(feature = "sha2")]
+    use sha2::Sha256;
+
+    #[cfg(feature = "sha2")]
+    const ABC_SHA256: [u8; 32] = [
+        0xBA, 0x78, 0x16, 0xBF, 0x8F, 0x01, 0xCF, 0xEA, 0x41, 0x41, 0x40, 0xDE, 0x5D, 0xAE, 0x22,
+        0x23, 0xB0, 0x03, 0x61, 0xA3, 0x96, 0x17, 0x7A, 0x9C, 0xB4, 0x10, 0xFF, 0x61, 0xF2, 0x00,
+        0x15, 0xAD,
+    ];
+
+    #[cfg(feature = "sha2")]
+    #[test]
+    fn write_abc() {
+        let mut buffer = Vec::new();
+        let ((), digest) = write_into_hashed::<Sha256, _>(&mut buffer, Plain("abc")).unwrap();
+        assert_eq!(digest[..], ABC_SHA256);
+        assert_eq!(&buffer, b"abc");
+    }
+
+    #[cfg(feature = "sha2")]
+    #[test]
+    fn write_abc_in_pieces() {
+        let mut buffer = Vec::new();
+        let mut sink = HashingSink::<Sha256, _>::new(&mut buffer);
+        write_into(&mut sink, Plain("a")).unwrap();
+        write_into(&mut sink, Plain("bc")).unwrap();
+        let (_, digest) = sink.finalize();
+        assert_eq!(digest[..], ABC_SHA256);
+        assert_eq!(&buffer, b"abc");
+    }
+
+    #[cfg(feature = "blake3")]
+    #[test]
+    fn write_abc_blake3() {
+        let mut buffer = Vec::new();
+        let ((), hash) = write_into_blake3(&mut buffer, Plain("abc")).unwrap();
+        assert_eq!(
+            hash,
+            [
+                0x64, 0x37, 0xB3, 0xAC, 0x38, 0x46, 0x51, 0x33, 0xFF, 0xB6, 0x3B, 0x75, 0x27, 0x3A,
+                0x8D, 0xB5, 0x48, 0xC5, 0x58, 0x46, 0x5D, 0x79, 0xDB, 0x03, 0xFD, 0x35, 0x9C, 0x6C,
+                0xD5, 0xBD, 0x9D, 0x85
+            ]
+        );
+        assert_eq!(&buffer, b"abc");
+    }
+}
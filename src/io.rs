@@ -0,0 +1,124 @@
+//! A minimal subset of `std::io` used when the `std` feature is disabled.
+
+use core::fmt;
+
+/// A specialized [`Result`](core::result::Result) type for I/O operations.
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// A list specifying general categories of I/O error.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// A parameter was incorrect.
+    InvalidInput,
+    /// A write returned `Ok(0)`.
+    WriteZero,
+    /// The operation was interrupted and can typically be retried.
+    Interrupted,
+    /// Any other I/O error.
+    Other,
+}
+
+/// The error type for I/O operations.
+#[derive(Debug)]
+pub struct Error {
+    kind: ErrorKind,
+    message: &'static str,
+}
+
+impl Error {
+    /// Creates a new I/O error from a known kind of error and a message.
+    pub fn new(kind: ErrorKind, message: &'static str) -> Self {
+        Self { kind, message }
+    }
+
+    /// Returns the corresponding [`ErrorKind`] for this error.
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+}
+
+impl From<ErrorKind> for Error {
+    fn from(kind: ErrorKind) -> Self {
+        Self::new(kind, "")
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.message.is_empty() {
+            fmt::Debug::fmt(&self.kind, f)
+        } else {
+            f.write_str(self.message)
+        }
+    }
+}
+
+/// A trait for objects which are byte-oriented sinks.
+pub trait Write {
+    /// Writes a buffer into this writer, returning how many bytes were written.
+    fn write(&mut self, buf: &[u8]) -> Result<usize>;
+
+    /// Flushes this output stream.
+    fn flush(&mut self) -> Result<()>;
+
+    /// Attempts to write an entire buffer into this writer.
+    fn write_all(&mut self, mut buf: &[u8]) -> Result<()> {
+        while !buf.is_empty() {
+            match self.write(buf) {
+                Ok(0) => {
+                    return Err(Error::new(
+                        ErrorKind::WriteZero,
+                        "failed to write whole buffer",
+                    ));
+                }
+                Ok(written) => buf = &buf[written..],
+                Err(error) if error.kind() == ErrorKind::Interrupted => {}
+                Err(error) => return Err(error),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<W> Write for &mut W
+where
+    W: Write + ?Sized,
+{
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        (**self).write(buf)
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        (**self).flush()
+    }
+}
+
+/// Writes into the slice, advancing it past the written bytes.
+impl Write for &mut [u8] {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        let written = buf.len().min(self.len());
+        let (head, tail) = core::mem::take(self).split_at_mut(written);
+        head.copy_from_slice(&buf[..written]);
+        *self = tail;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Appends the bytes to the vector _(requires `alloc` feature)_.
+#[cfg(feature = "alloc")]
+impl Write for alloc::vec::Vec<u8> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        self.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
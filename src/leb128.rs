@@ -1,12 +1,12 @@
-use super::{write_into, WriteInto};
-use std::io;
-use std::mem::{size_of, MaybeUninit};
+use super::{io, write_into, WriteInto};
+use core::mem::{size_of, MaybeUninit};
 
 /// Used to write values in LEB-128 format _(unsigned)_.
 ///
 /// # Example
 ///
-/// ```
+#[cfg_attr(feature = "alloc", doc = "```")]
+#[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
 /// use write_into::{Uleb128, write_into};
 ///
 /// let mut buffer = Vec::new();
@@ -19,7 +19,8 @@ pub struct Uleb128<T>(pub T);
 ///
 /// # Example
 ///
-/// ```
+#[cfg_attr(feature = "alloc", doc = "```")]
+#[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
 /// use write_into::{Sleb128, write_into};
 ///
 /// let mut buffer = Vec::new();
@@ -28,6 +29,42 @@ pub struct Uleb128<T>(pub T);
 /// ```
 pub struct Sleb128<T>(pub T);
 
+/// Used to write values in LEB-128 format after zigzag encoding _(signed)_.
+///
+/// Zigzag encoding maps signed integers to unsigned ones so that values of small magnitude stay
+/// small (`0`, `-1`, `1`, `-2` become `0`, `1`, `2`, `3`), as in Protobuf `sint32`/`sint64`.
+///
+/// # Example
+///
+#[cfg_attr(feature = "alloc", doc = "```")]
+#[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+/// use write_into::{ZigzagLeb128, write_into};
+///
+/// let mut buffer = Vec::new();
+/// write_into(&mut buffer, ZigzagLeb128(-69i32)).unwrap();
+/// assert_eq!(&buffer, &[0x89, 0x01]);
+/// ```
+pub struct ZigzagLeb128<T>(pub T);
+
+/// Used to write values in LEB-128 format padded to a fixed width _(unsigned)_.
+///
+/// The second field is the width in bytes. High septets are padded with zeros, so the value
+/// always takes the same number of bytes and can be overwritten later. Writing a value which
+/// does not fit into the width fails with [`io::ErrorKind::InvalidInput`].
+///
+/// # Example
+///
+#[cfg_attr(feature = "alloc", doc = "```")]
+#[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+/// use write_into::{PaddedUleb128, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, PaddedUleb128(69u32, 3)).unwrap();
+/// assert_eq!(written, 3);
+/// assert_eq!(&buffer, &[0xC5, 0x80, 0x00]);
+/// ```
+pub struct PaddedUleb128<T>(pub T, pub usize);
+
 macro_rules! impl_write_into {
     ($($wrapper:ident => { $($primitive:ident)* }),*,) => {
         $(
@@ -152,6 +189,93 @@ impl_write_into! {
     },
 }
 
+macro_rules! impl_zigzag {
+    ($($signed:ident => $unsigned:ident),*,) => {
+        $(
+            impl WriteInto for ZigzagLeb128<$signed> {
+                type Output = usize;
+
+                fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+                    let zigzag = (self.0 << 1) ^ (self.0 >> ($signed::BITS - 1));
+                    write_into(sink, Uleb128(zigzag as $unsigned))
+                }
+            }
+
+            impl WriteInto for &ZigzagLeb128<$signed> {
+                type Output = usize;
+
+                fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+                    write_into(sink, ZigzagLeb128(self.0))
+                }
+            }
+        )*
+    };
+}
+
+impl_zigzag! {
+    i8 => u8,
+    i16 => u16,
+    i32 => u32,
+    i64 => u64,
+    i128 => u128,
+    isize => usize,
+}
+
+macro_rules! impl_padded {
+    ($($primitive:ident)*) => {
+        $(
+            impl WriteInto for PaddedUleb128<$primitive> {
+                type Output = usize;
+
+                fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+                    let bits = ($primitive::BITS - self.0.leading_zeros()).max(1);
+                    let required = count_bits_in_chunks(bits as usize, 7);
+                    if required > self.1 {
+                        return Err(io::Error::new(
+                            io::ErrorKind::InvalidInput,
+                            "value does not fit into the width",
+                        ));
+                    }
+
+                    // The width is unbounded, so wide padding is written in chunks.
+                    let mut buffer = [0u8; 32];
+                    let mut value = self.0;
+                    let mut remaining = self.1;
+                    while remaining > 0 {
+                        let chunk = remaining.min(buffer.len());
+                        for byte in &mut buffer[..chunk] {
+                            *byte = 0x80 | value as u8 & 0x7F;
+                            value = value.checked_shr(7).unwrap_or(0);
+                        }
+
+                        remaining -= chunk;
+                        if remaining == 0 {
+                            buffer[chunk - 1] &= 0x7F;
+                        }
+
+                        sink.write_all(&buffer[..chunk])?;
+                    }
+
+                    Ok(self.1)
+                }
+            }
+
+            impl WriteInto for &PaddedUleb128<$primitive> {
+                type Output = usize;
+
+                fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+                    write_into(sink, PaddedUleb128(self.0, self.1))
+                }
+            }
+        )*
+    };
+}
+
+impl_padded! {
+    u8 u16 u32 u64 u128 usize
+}
+
+#[allow(clippy::identity_op)]
 const fn max_leb128_size(bytes: usize) -> usize {
     let bits = bytes * 8;
     let septets = count_bits_in_chunks(bits, 7);
@@ -166,7 +290,7 @@ const fn count_bits_in_chunks(bits: usize, chunk_size: usize) -> usize {
     chunks + if remaining != 0 { 1 } else { 0 }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use super::super::*;
     use super::*;
@@ -227,4 +351,52 @@ mod tests {
         write_into(&mut buffer, Sleb128(number)).unwrap();
         buffer
     }
+
+    #[test_case(          0 => using vec(&[ 0x00                         ]); "when           0" )]
+    #[test_case(         -1 => using vec(&[ 0x01                         ]); "when minus     1" )]
+    #[test_case(          1 => using vec(&[ 0x02                         ]); "when           1" )]
+    #[test_case(         -2 => using vec(&[ 0x03                         ]); "when minus     2" )]
+    #[test_case(         63 => using vec(&[ 0x7E                         ]); "when          63" )]
+    #[test_case(        -64 => using vec(&[ 0x7F                         ]); "when minus    64" )]
+    #[test_case(         64 => using vec(&[ 0x80, 0x01                   ]); "when          64" )]
+    #[test_case(   i32::MAX => using vec(&[ 0xFE, 0xFF, 0xFF, 0xFF, 0x0F ]); "when         max" )]
+    #[test_case(   i32::MIN => using vec(&[ 0xFF, 0xFF, 0xFF, 0xFF, 0x0F ]); "when         min" )]
+    fn write_zigzag_i32(number: i32) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, ZigzagLeb128(number)).unwrap();
+        assert_eq!(written, buffer.len());
+        buffer
+    }
+
+    #[test_case(          1, 1 => using vec(&[ 0x01                         ]); "when          1 in 1" )]
+    #[test_case(          1, 5 => using vec(&[ 0x81, 0x80, 0x80, 0x80, 0x00 ]); "when          1 in 5" )]
+    #[test_case(          0, 2 => using vec(&[ 0x80, 0x00                   ]); "when          0 in 2" )]
+    #[test_case(        128, 2 => using vec(&[ 0x80, 0x01                   ]); "when        128 in 2" )]
+    #[test_case(        300, 4 => using vec(&[ 0xAC, 0x82, 0x80, 0x00       ]); "when        300 in 4" )]
+    #[test_case(   u32::MAX, 5 => using vec(&[ 0xFF, 0xFF, 0xFF, 0xFF, 0x0F ]); "when        max in 5" )]
+    fn write_padded_u32(number: u32, width: usize) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, PaddedUleb128(number, width)).unwrap();
+        assert_eq!(written, width);
+        buffer
+    }
+
+    #[test]
+    fn write_padded_wider_than_buffer() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, PaddedUleb128(300u32, 70)).unwrap();
+        assert_eq!(written, 70);
+        assert_eq!(&buffer[..2], &[0xAC, 0x82]);
+        assert!(buffer[2..69].iter().all(|&byte| byte == 0x80));
+        assert_eq!(buffer[69], 0x00);
+    }
+
+    #[test_case( 128, 1; "when 128 in 1" )]
+    #[test_case(   0, 0; "when   0 in 0" )]
+    fn write_padded_u32_overflow(number: u32, width: usize) {
+        let mut buffer = Vec::new();
+        let error = write_into(&mut buffer, PaddedUleb128(number, width)).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert!(buffer.is_empty());
+    }
 }
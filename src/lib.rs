@@ -14,30 +14,341 @@
 //!
 //! # Example
 //!
-//! ```
+#![cfg_attr(feature = "alloc", doc = "```")]
+#![cfg_attr(not(feature = "alloc"), doc = "```ignore")]
 //! use write_into::{BigEndian, write_into};
 //!
 //! let mut buffer = Vec::new();
 //! write_into(&mut buffer, BigEndian(0xCAFEBABEu32)).unwrap();
 //! assert_eq!(&buffer, &[0xCA, 0xFE, 0xBA, 0xBE]);
 //! ```
+//!
+//! # `no_std`
+//!
+//! Disabling the default `std` feature makes the crate `no_std`. The trait is then built on top of
+//! a minimal `io::Write` defined by the crate, which is implemented for `&mut [u8]` and, with the
+//! `alloc` feature, for `Vec<u8>`. Only [`BigEndian`], [`LittleEndian`], [`Endian`],
+//! [`StoredLe`], [`Plain`], the LEB-128 wrappers, [`Sequence`], [`SizedSequence`],
+//! [`SeparatedSequence`], [`Repeat`], [`CountingWriter`], tuples, [`Option`] and
+//! [`Duration`](core::time::Duration) are available without `std`. The `alloc` feature adds the
+//! wrappers buffering their representations, such as `Sized`, `FooterSized`, `Tlv` and
+//! `ProtoPackedField`.
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+extern crate alloc;
+
+#[cfg(feature = "std")]
+mod aligned;
+#[cfg(feature = "tokio")]
+mod async_io;
+#[cfg(feature = "alloc")]
+mod base32;
+#[cfg(feature = "std")]
+mod bencode;
+#[cfg(feature = "std")]
+mod bits;
+#[cfg(feature = "std")]
+mod bracketed;
+#[cfg(feature = "alloc")]
+mod bson;
+#[cfg(feature = "std")]
+mod capped;
+#[cfg(feature = "alloc")]
+mod capture;
+#[cfg(feature = "std")]
+mod cbor;
+#[cfg(feature = "std")]
+mod checksum;
+#[cfg(feature = "std")]
+mod cobs;
+mod counting;
+#[cfg(feature = "std")]
+mod delta;
 mod endianness;
+#[cfg(feature = "std")]
+mod escaped;
+#[cfg(feature = "std")]
+mod float;
+#[cfg(feature = "std")]
+mod git;
+#[cfg(feature = "flate2")]
+mod gzip;
+#[cfg(any(feature = "blake3", feature = "sha2"))]
+mod hashing;
+#[cfg(not(feature = "std"))]
+pub mod io;
 mod leb128;
+#[cfg(feature = "lz4")]
+mod lz4;
+#[cfg(feature = "std")]
+mod map_err;
+#[cfg(feature = "memmap2")]
+mod mmap;
+#[cfg(feature = "std")]
+mod morton;
+#[cfg(feature = "std")]
+mod msgpack;
+#[cfg(feature = "alloc")]
+mod ndjson;
+#[cfg(feature = "std")]
+mod net;
+#[cfg(feature = "std")]
+mod ntp;
+#[cfg(feature = "std")]
+mod null_terminated;
+mod option;
+#[cfg(feature = "std")]
+mod padding;
 mod plain;
+#[cfg(feature = "std")]
+mod png;
+#[cfg(feature = "alloc")]
+mod protobuf;
+#[cfg(feature = "std")]
+mod range;
+#[cfg(feature = "std")]
+mod resp;
+#[cfg(feature = "alloc")]
+mod reversed;
+#[cfg(feature = "std")]
+mod rle;
+#[cfg(feature = "std")]
+mod scaled;
 mod sequence;
+#[cfg(feature = "std")]
+mod sign_magnitude;
+#[cfg(feature = "alloc")]
 mod sized;
+#[cfg(feature = "std")]
+mod tar;
+#[cfg(feature = "std")]
+mod terminated;
+#[cfg(feature = "std")]
+mod text;
+mod time;
+mod tuple;
+#[cfg(feature = "std")]
+mod url;
+#[cfg(feature = "std")]
+mod utf16;
+#[cfg(feature = "uuid")]
+mod uuid;
+#[cfg(feature = "std")]
+mod varint;
+#[cfg(feature = "std")]
+mod wav;
+#[cfg(feature = "std")]
+mod websocket;
+#[cfg(feature = "std")]
+mod xor;
 
-use std::io;
+#[cfg(feature = "std")]
+use std::io::{self, Read};
+#[cfg(feature = "std")]
+use std::ops::Range;
 
+#[cfg(feature = "uuid")]
+pub use self::uuid::UuidBinary;
+#[cfg(feature = "uuid")]
+pub use self::uuid::UuidText;
+#[cfg(feature = "std")]
+pub use aligned::AlignedWriter;
+#[cfg(feature = "std")]
+pub use aligned::RequireAligned;
+#[cfg(feature = "tokio")]
+pub use async_io::write_into_async;
+#[cfg(feature = "tokio")]
+pub use async_io::AsyncWriteInto;
+#[cfg(feature = "alloc")]
+pub use base32::Base32;
+#[cfg(feature = "std")]
+pub use bencode::BencodeBytes;
+#[cfg(feature = "std")]
+pub use bencode::BencodeInt;
+#[cfg(feature = "std")]
+pub use bits::write_into_bits;
+#[cfg(feature = "std")]
+pub use bits::Bit;
+#[cfg(feature = "std")]
+pub use bits::BitWriter;
+#[cfg(feature = "std")]
+pub use bits::Bits;
+#[cfg(feature = "std")]
+pub use bits::WriteIntoBits;
+#[cfg(feature = "std")]
+pub use bracketed::Bracketed;
+#[cfg(feature = "alloc")]
+pub use bson::BsonDocument;
+#[cfg(feature = "std")]
+pub use capped::CappedSink;
+#[cfg(feature = "std")]
+pub use capped::Guarded;
+#[cfg(feature = "alloc")]
+pub use capture::write_into_capturing;
+#[cfg(feature = "std")]
+pub use cbor::CborHead;
+#[cfg(feature = "std")]
+pub use cbor::CborMap;
+#[cfg(feature = "std")]
+pub use checksum::Crc16Modbus;
+#[cfg(feature = "std")]
+pub use checksum::Fletcher16Checksummed;
+#[cfg(feature = "std")]
+pub use cobs::Cobs;
+pub use counting::CountingWriter;
+#[cfg(feature = "std")]
+pub use delta::ZigZagDeltaSequence;
 pub use endianness::BigEndian;
+pub use endianness::Endian;
 pub use endianness::LittleEndian;
+pub use endianness::StoredLe;
+#[cfg(feature = "std")]
+pub use escaped::Escaped;
+#[cfg(feature = "std")]
+pub use float::FloatArray;
+#[cfg(feature = "std")]
+pub use float::FlushDenormal;
+#[cfg(feature = "std")]
+pub use git::GitObjectHeader;
+#[cfg(feature = "std")]
+pub use git::GitTreeEntry;
+#[cfg(feature = "flate2")]
+pub use gzip::GzipMember;
+#[cfg(feature = "blake3")]
+pub use hashing::write_into_blake3;
+#[cfg(feature = "sha2")]
+pub use hashing::write_into_hashed;
+#[cfg(feature = "sha2")]
+pub use hashing::HashingSink;
+pub use leb128::PaddedUleb128;
 pub use leb128::Sleb128;
 pub use leb128::Uleb128;
+pub use leb128::ZigzagLeb128;
+#[cfg(feature = "lz4")]
+pub use lz4::Lz4Block;
+#[cfg(feature = "std")]
+pub use map_err::MapErr;
+#[cfg(feature = "memmap2")]
+pub use mmap::write_into_mmap;
+#[cfg(feature = "std")]
+pub use morton::Morton2D;
+#[cfg(feature = "std")]
+pub use msgpack::MsgPackInt;
+#[cfg(feature = "std")]
+pub use msgpack::MsgPackMap;
+#[cfg(feature = "alloc")]
+pub use ndjson::Ndjson;
+#[cfg(feature = "std")]
+pub use net::DnsName;
+#[cfg(feature = "std")]
+pub use net::IpText;
+#[cfg(feature = "std")]
+pub use net::Socks5Addr;
+#[cfg(feature = "std")]
+pub use ntp::NtpTimestamp;
+#[cfg(feature = "std")]
+pub use null_terminated::NullTerminated;
+#[cfg(feature = "std")]
+pub use padding::NullPaddedField;
+#[cfg(feature = "std")]
+pub use padding::PadTo;
+#[cfg(feature = "std")]
+pub use padding::PadToPow2;
 pub use plain::Plain;
+#[cfg(feature = "std")]
+pub use png::PngChunk;
+#[cfg(feature = "alloc")]
+pub use protobuf::ProtoPackedField;
+#[cfg(feature = "std")]
+pub use range::OffsetLength;
+#[cfg(feature = "std")]
+pub use resp::RespArray;
+#[cfg(feature = "std")]
+pub use resp::RespBulkString;
+#[cfg(feature = "std")]
+pub use resp::RespNull;
+#[cfg(feature = "alloc")]
+pub use reversed::FullyReversed;
+#[cfg(feature = "std")]
+pub use rle::RleBitmap;
+#[cfg(feature = "std")]
+pub use scaled::Scaled;
+pub use sequence::Repeat;
+pub use sequence::SeparatedSequence;
 pub use sequence::Sequence;
 pub use sequence::SizedSequence;
+#[cfg(feature = "alloc")]
+pub use sequence::SortedRun;
+#[cfg(feature = "alloc")]
+pub use sequence::StridedSequence;
+#[cfg(feature = "std")]
+pub use sign_magnitude::SignMagnitude;
+#[cfg(feature = "alloc")]
+pub use sized::AlignedSizedChunk;
+#[cfg(feature = "alloc")]
+pub use sized::CountAndSizePrefixed;
+#[cfg(feature = "alloc")]
+pub use sized::FooterSized;
+#[cfg(feature = "alloc")]
 pub use sized::Sized;
+#[cfg(feature = "std")]
+pub use sized::StreamedSized;
+#[cfg(feature = "alloc")]
+pub use sized::Tlv;
+#[cfg(feature = "alloc")]
+pub use sized::U32BePrefixed;
+#[cfg(feature = "std")]
+pub use tar::TarHeader;
+#[cfg(feature = "std")]
+pub use terminated::CrlfTerminated;
+#[cfg(feature = "std")]
+pub use text::Ascii7;
+#[cfg(feature = "std")]
+pub use text::BoundedStr;
+#[cfg(feature = "std")]
+pub use text::FillLine;
+#[cfg(feature = "std")]
+pub use text::OctalField;
+#[cfg(feature = "std")]
+pub use text::PrefixedHex;
+#[cfg(feature = "std")]
+pub use text::RustCharLiteral;
+#[cfg(feature = "std")]
+pub use text::ShortestFloat;
+#[cfg(feature = "std")]
+pub use text::Timecode;
+#[cfg(feature = "std")]
+pub use url::PercentEncoded;
+#[cfg(feature = "std")]
+pub use url::QueryString;
+#[cfg(feature = "std")]
+pub use utf16::LenPrefixedUtf16;
+#[cfg(feature = "std")]
+pub use utf16::Utf16;
+#[cfg(feature = "std")]
+pub use varint::CompactSize;
+#[cfg(feature = "std")]
+pub use varint::CompactUint;
+#[cfg(feature = "std")]
+pub use varint::GitOffsetVarint;
+#[cfg(feature = "std")]
+pub use varint::MidiVlq;
+#[cfg(feature = "std")]
+pub use varint::MinimalSigned;
+#[cfg(feature = "std")]
+pub use varint::SyncSafeU32;
+#[cfg(feature = "std")]
+pub use wav::RiffPadded;
+#[cfg(feature = "std")]
+pub use wav::WavDataChunk;
+#[cfg(feature = "std")]
+pub use wav::WavHeader;
+#[cfg(feature = "std")]
+pub use websocket::WsFrameHeader;
+#[cfg(feature = "std")]
+pub use xor::XorMasked;
 
 /// Writes value into I/O sink.
 pub trait WriteInto {
@@ -70,8 +381,159 @@ pub fn write_into<T: WriteInto>(sink: &mut impl io::Write, value: T) -> io::Resu
 /// assert_eq!(aligned_position, 4);
 /// assert_eq!(buffer.get_ref(), &[0xAA, 0xBB, 0x00, 0x00, 0xCC, 0xDD]);
 /// ```
+#[cfg(feature = "std")]
 pub fn align_position(sink: &mut impl io::Seek, boundary: u64) -> io::Result<u64> {
     let position = sink.stream_position()?;
     let alignment = boundary - (position + boundary) % boundary;
     sink.seek(io::SeekFrom::Current(alignment as i64))
 }
+
+/// Aligns position in the I/O sink to the given boundary by writing the fill byte and returns a
+/// new position.
+///
+/// Unlike [`align_position`], the skipped bytes are overwritten and an already aligned position
+/// is left as is.
+///
+/// # Example
+///
+/// ```
+/// use std::io;
+/// use write_into::{BigEndian, align_position_with, write_into};
+///
+/// let mut buffer = io::Cursor::new(Vec::new());
+/// write_into(&mut buffer, BigEndian(0xAABBu16)).unwrap();
+/// let aligned_position = align_position_with(&mut buffer, 4, 0xFF).unwrap();
+/// write_into(&mut buffer, BigEndian(0xCCDDu16)).unwrap();
+/// assert_eq!(aligned_position, 4);
+/// assert_eq!(buffer.get_ref(), &[0xAA, 0xBB, 0xFF, 0xFF, 0xCC, 0xDD]);
+/// ```
+#[cfg(feature = "std")]
+pub fn align_position_with<W>(sink: &mut W, boundary: u64, fill: u8) -> io::Result<u64>
+where
+    W: io::Write + io::Seek,
+{
+    let position = sink.stream_position()?;
+    let alignment = (boundary - position % boundary) % boundary;
+    io::copy(&mut io::repeat(fill).take(alignment), sink)?;
+    Ok(position + alignment)
+}
+
+/// Writes value into I/O sink and returns the range of positions occupied by its representation.
+///
+/// # Example
+///
+/// ```
+/// use std::io;
+/// use write_into::{BigEndian, Plain, write_into_ranged};
+///
+/// let mut buffer = io::Cursor::new(Vec::new());
+/// let (first, _) = write_into_ranged(&mut buffer, BigEndian(0xAABBu16)).unwrap();
+/// let (second, ()) = write_into_ranged(&mut buffer, Plain("Hello")).unwrap();
+/// assert_eq!(first, 0..2);
+/// assert_eq!(second, 2..7);
+/// assert_eq!(buffer.get_ref(), b"\xAA\xBBHello");
+/// ```
+#[cfg(feature = "std")]
+pub fn write_into_ranged<W, T>(sink: &mut W, value: T) -> io::Result<(Range<u64>, T::Output)>
+where
+    W: io::Write + io::Seek,
+    T: WriteInto,
+{
+    let start = sink.stream_position()?;
+    let output = value.write_into(sink)?;
+    let end = sink.stream_position()?;
+    Ok((start..end, output))
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn align_with_fill_over_existing_bytes() {
+        let mut buffer = io::Cursor::new(vec![0xFF; 8]);
+        write_into(&mut buffer, BigEndian(0xAAu8)).unwrap();
+        let aligned_position = align_position_with(&mut buffer, 4, 0x00).unwrap();
+        assert_eq!(aligned_position, 4);
+        assert_eq!(buffer.position(), 4);
+        assert_eq!(
+            buffer.get_ref(),
+            &[0xAA, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0xFF]
+        );
+    }
+
+    #[test]
+    fn align_with_fill_when_already_aligned() {
+        let mut buffer = io::Cursor::new(Vec::new());
+        write_into(&mut buffer, BigEndian(0xAABBCCDDu32)).unwrap();
+        assert_eq!(align_position_with(&mut buffer, 4, 0xFF).unwrap(), 4);
+        assert_eq!(align_position_with(&mut buffer, 2, 0xFF).unwrap(), 4);
+        assert_eq!(buffer.get_ref().len(), 4);
+    }
+}
+
+#[cfg(all(test, not(feature = "std")))]
+mod no_std_tests {
+    use super::*;
+
+    #[test]
+    fn write_into_slice() {
+        let mut buffer = [0u8; 8];
+        let mut sink = &mut buffer[..];
+        assert_eq!(write_into(&mut sink, BigEndian(0xCAFEu16)).unwrap(), 2);
+        assert_eq!(write_into(&mut sink, Uleb128(300u32)).unwrap(), 2);
+        write_into(&mut sink, (Plain("Hi"), Some(LittleEndian(0xBEEFu16)))).unwrap();
+        assert!(sink.is_empty());
+        assert_eq!(&buffer, b"\xCA\xFE\xAC\x02Hi\xEF\xBE");
+    }
+
+    #[test]
+    fn write_into_full_slice() {
+        let mut buffer = [0u8; 3];
+        let mut sink = &mut buffer[..];
+        let error = write_into(&mut sink, BigEndian(0xCAFEBABEu32)).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::WriteZero);
+    }
+
+    #[test]
+    fn write_sequences_into_slice() {
+        let mut buffer = [0u8; 12];
+        let mut sink = CountingWriter::new(&mut buffer[..]);
+        write_into(&mut sink, Sequence([0xAAu8, 0xBB].map(BigEndian))).unwrap();
+        write_into(
+            &mut sink,
+            SizedSequence(|count| BigEndian(count as u16), [Plain("c")]),
+        )
+        .unwrap();
+        write_into(&mut sink, Repeat(Plain("ab"), 2)).unwrap();
+        write_into(
+            &mut sink,
+            SeparatedSequence(["x", "y"].map(Plain), Plain(",")),
+        )
+        .unwrap();
+        assert_eq!(sink.count(), 12);
+        assert_eq!(&buffer, b"\xAA\xBB\x00\x01cababx,y");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn write_into_vec() {
+        let mut buffer = alloc::vec::Vec::new();
+        write_into(&mut buffer, LittleEndian(&[0x0102u16, 0x0304][..])).unwrap();
+        assert_eq!(&buffer, &[0x02, 0x01, 0x04, 0x03]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn write_sized_into_vec() {
+        let mut buffer = alloc::vec::Vec::new();
+        write_into(&mut buffer, Sized(Uleb128, Plain("Hi"))).unwrap();
+        write_into(&mut buffer, ProtoPackedField(1, [1u8, 2])).unwrap();
+        write_into(
+            &mut buffer,
+            StridedSequence([0xAAu8].map(BigEndian), 2, 0xFF),
+        )
+        .unwrap();
+        assert_eq!(&buffer, b"\x02Hi\x0A\x02\x01\x02\xAA\xFF");
+    }
+}
@@ -0,0 +1,78 @@
+use super::{write_into, LittleEndian, Plain, WriteInto};
+use std::io;
+
+/// Used to write values compressed as an LZ4 block _(requires `lz4` feature)_.
+///
+/// The block is prepended with the size of the uncompressed representation as little endian
+/// [`u32`]. Compressing a representation larger than [`u32::MAX`] bytes fails with
+/// [`io::ErrorKind::InvalidInput`].
+///
+/// # Example
+///
+/// ```
+/// use write_into::{Lz4Block, Plain, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, Lz4Block(Plain("aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa"))).unwrap();
+/// assert_eq!(&buffer[..4], &[0x20, 0x00, 0x00, 0x00]);
+/// assert_eq!(written, buffer.len() - 4);
+/// assert!(written < 32);
+/// ```
+pub struct Lz4Block<T>(pub T)
+where
+    T: WriteInto;
+
+/// Returns how many bytes was taken by the compressed representation of `T` (excluding the
+/// size prefix).
+impl<T> WriteInto for Lz4Block<T>
+where
+    T: WriteInto,
+{
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let mut buffer = Vec::new();
+        write_into(&mut buffer, self.0)?;
+        let size = u32::try_from(buffer.len()).map_err(|_| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "representation is larger than u32::MAX bytes",
+            )
+        })?;
+
+        let compressed = lz4_flex::block::compress(&buffer);
+        write_into(sink, LittleEndian(size))?;
+        write_into(sink, Plain(&compressed[..]))?;
+        Ok(compressed.len())
+    }
+}
+
+/// Returns how many bytes was taken by the compressed representation of `T` (excluding the
+/// size prefix).
+impl<T> WriteInto for &Lz4Block<T>
+where
+    T: Copy + WriteInto,
+{
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, Lz4Block(self.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+
+    #[test]
+    fn round_trip() {
+        let text = "Hello, Sailor! ".repeat(16);
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, Lz4Block(Plain(&*text))).unwrap();
+        assert_eq!(written, buffer.len() - 4);
+        assert_eq!(&buffer[..4], &(text.len() as u32).to_le_bytes());
+
+        let decompressed = lz4_flex::block::decompress(&buffer[4..], text.len()).unwrap();
+        assert_eq!(decompressed, text.as_bytes());
+    }
+}
@@ -0,0 +1,83 @@
+use super::{write_into, WriteInto};
+use std::io;
+
+/// Used to write values mapping errors of the write with the given function.
+///
+/// # Example
+///
+/// ```
+/// use std::io;
+/// use write_into::{BoundedStr, MapErr, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let error = write_into(&mut buffer, MapErr(BoundedStr("Hello", 4), |error| {
+///     io::Error::new(error.kind(), format!("greeting: {}", error))
+/// })).unwrap_err();
+/// assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+/// assert_eq!(error.to_string(), "greeting: string is longer than the limit");
+/// ```
+pub struct MapErr<T, F>(pub T, pub F)
+where
+    T: WriteInto,
+    F: FnOnce(io::Error) -> io::Error;
+
+/// Returns the output of writing `T`.
+impl<T, F> WriteInto for MapErr<T, F>
+where
+    T: WriteInto,
+    F: FnOnce(io::Error) -> io::Error,
+{
+    type Output = T::Output;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, self.0).map_err(self.1)
+    }
+}
+
+/// Returns the output of writing `T`.
+impl<T, F> WriteInto for &MapErr<T, F>
+where
+    T: Copy + WriteInto,
+    F: Copy + FnOnce(io::Error) -> io::Error,
+{
+    type Output = T::Output;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, MapErr(self.0, self.1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+
+    #[test]
+    fn map_error_with_context() {
+        let mut buffer = Vec::new();
+        let mut sink = CappedSink::new(&mut buffer, 2);
+        let error = write_into(
+            &mut sink,
+            MapErr(BigEndian(0xCAFEBABEu32), |error| {
+                io::Error::new(error.kind(), format!("while writing magic: {}", error))
+            }),
+        )
+        .unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::WriteZero);
+        assert_eq!(
+            error.to_string(),
+            "while writing magic: size cap of the sink is exhausted"
+        );
+    }
+
+    #[test]
+    fn keep_output_on_success() {
+        let mut buffer = Vec::new();
+        let written = write_into(
+            &mut buffer,
+            MapErr(BigEndian(0xCAFEu16), |_| unreachable!()),
+        )
+        .unwrap();
+        assert_eq!(written, 2);
+        assert_eq!(&buffer, &[0xCA, 0xFE]);
+    }
+}
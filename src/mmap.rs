@@ -0,0 +1,81 @@
+use super::{write_into, WriteInto};
+use memmap2::MmapMut;
+use std::io;
+
+/// Writes value into memory-mapped region at the given offset and returns how many bytes was
+/// written along with the output _(requires `memmap2` feature)_.
+///
+/// Writing at an offset past the end of the mapping fails with [`io::ErrorKind::InvalidInput`].
+/// A representation exceeding the mapping fails with [`io::ErrorKind::WriteZero`], leaving the
+/// bytes which fit written.
+///
+/// # Example
+///
+/// ```
+/// use memmap2::MmapMut;
+/// use write_into::{BigEndian, write_into_mmap};
+///
+/// let mut map = MmapMut::map_anon(8).unwrap();
+/// let (written, _) = write_into_mmap(&mut map, 2, BigEndian(0xCAFEu16)).unwrap();
+/// assert_eq!(written, 2);
+/// assert_eq!(&map[..], &[0x00, 0x00, 0xCA, 0xFE, 0x00, 0x00, 0x00, 0x00]);
+/// ```
+pub fn write_into_mmap<T>(
+    map: &mut MmapMut,
+    offset: usize,
+    value: T,
+) -> io::Result<(usize, T::Output)>
+where
+    T: WriteInto,
+{
+    let region = map.get_mut(offset..).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "offset is past the end of the mapping",
+        )
+    })?;
+
+    let mut cursor = io::Cursor::new(region);
+    let output = write_into(&mut cursor, value)?;
+    Ok((cursor.position() as usize, output))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use super::*;
+    use std::fs::{self, OpenOptions};
+
+    #[test]
+    fn write_into_file_mapping() {
+        let path = std::env::temp_dir().join(format!("write-into-mmap-{}", std::process::id()));
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(&path)
+            .unwrap();
+        file.set_len(8).unwrap();
+
+        let mut map = unsafe { MmapMut::map_mut(&file) }.unwrap();
+        let (written, _) = write_into_mmap(&mut map, 4, BigEndian(0xCAFEBABEu32)).unwrap();
+        map.flush().unwrap();
+        drop(map);
+
+        let contents = fs::read(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(written, 4);
+        assert_eq!(&contents, &[0x00, 0x00, 0x00, 0x00, 0xCA, 0xFE, 0xBA, 0xBE]);
+    }
+
+    #[test]
+    fn write_past_mapping() {
+        let mut map = MmapMut::map_anon(4).unwrap();
+        let error = write_into_mmap(&mut map, 2, BigEndian(0xCAFEBABEu32)).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::WriteZero);
+
+        let error = write_into_mmap(&mut map, 5, Plain("")).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+    }
+}
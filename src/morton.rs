@@ -0,0 +1,92 @@
+use super::{write_into, BigEndian, LittleEndian, WriteInto};
+use std::io;
+
+/// Used to write 2D coordinates as [`u32`] Morton (Z-order) codes.
+///
+/// The bits of `x` take the even positions of the code and the bits of `y` take the odd ones.
+/// The coordinates are wrapped into an endianness wrapper to choose the byte order of the code.
+///
+/// # Example
+///
+/// ```
+/// use write_into::{BigEndian, Morton2D, write_into};
+///
+/// let mut buffer = Vec::new();
+/// write_into(&mut buffer, BigEndian(Morton2D { x: 3, y: 5 })).unwrap();
+/// assert_eq!(&buffer, &[0x00, 0x00, 0x00, 0x27]);
+/// ```
+#[derive(Clone, Copy)]
+pub struct Morton2D {
+    /// Coordinate taking the even bits.
+    pub x: u16,
+    /// Coordinate taking the odd bits.
+    pub y: u16,
+}
+
+impl Morton2D {
+    /// Returns the Morton code of the coordinates.
+    pub fn code(self) -> u32 {
+        spread(self.x) | spread(self.y) << 1
+    }
+}
+
+macro_rules! impl_write_into {
+    ($($wrapper:ident)*) => {
+        $(
+            impl WriteInto for $wrapper<Morton2D> {
+                type Output = ();
+
+                fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+                    write_into(sink, $wrapper(self.0.code()))?;
+                    Ok(())
+                }
+            }
+
+            impl WriteInto for &$wrapper<Morton2D> {
+                type Output = ();
+
+                fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+                    write_into(sink, $wrapper(self.0))
+                }
+            }
+        )*
+    };
+}
+
+impl_write_into! {
+    BigEndian LittleEndian
+}
+
+/// Inserts a zero bit between every two bits of the number.
+fn spread(value: u16) -> u32 {
+    let mut value = value as u32;
+    value = (value | value << 8) & 0x00FF00FF;
+    value = (value | value << 4) & 0x0F0F0F0F;
+    value = (value | value << 2) & 0x33333333;
+    value = (value | value << 1) & 0x55555555;
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use test_case::test_case;
+
+    #[test_case(      0,      0 =>          0; "when origin"     )]
+    #[test_case(      1,      0 =>          1; "when x is 1"     )]
+    #[test_case(      0,      1 =>          2; "when y is 1"     )]
+    #[test_case(      2,      3 =>         14; "when 2 and 3"    )]
+    #[test_case(      3,      5 =>         39; "when 3 and 5"    )]
+    #[test_case( 0xFFFF,      0 => 0x55555555; "when x is max"   )]
+    #[test_case( 0xFFFF, 0xFFFF => 0xFFFFFFFF; "when both max"   )]
+    fn morton_code(x: u16, y: u16) -> u32 {
+        Morton2D { x, y }.code()
+    }
+
+    #[test]
+    fn write_morton_le() {
+        let mut buffer = Vec::new();
+        write_into(&mut buffer, LittleEndian(Morton2D { x: 0xFFFF, y: 0 })).unwrap();
+        assert_eq!(&buffer, &[0x55, 0x55, 0x55, 0x55]);
+    }
+}
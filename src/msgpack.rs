@@ -0,0 +1,221 @@
+use super::{write_into, BigEndian, Plain, WriteInto};
+use std::collections::BTreeMap;
+use std::io;
+
+/// Used to write integers in the most compact MessagePack integer format.
+///
+/// # Example
+///
+/// ```
+/// use write_into::{MsgPackInt, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, MsgPackInt(128)).unwrap();
+/// assert_eq!(written, 2);
+/// assert_eq!(&buffer, &[0xCC, 0x80]);
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+pub struct MsgPackInt(pub i64);
+
+/// Returns how many bytes was written.
+impl WriteInto for MsgPackInt {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let value = self.0;
+        match value {
+            // Positive and negative fixint.
+            -32..=127 => {
+                write_into(sink, Plain(&(value as u8)))?;
+                Ok(1)
+            }
+            128..=0xFF => {
+                write_into(sink, Plain(&[0xCC, value as u8]))?;
+                Ok(2)
+            }
+            0x100..=0xFFFF => {
+                write_into(sink, Plain(&0xCDu8))?;
+                write_into(sink, BigEndian(value as u16))?;
+                Ok(3)
+            }
+            0x1_0000..=0xFFFF_FFFF => {
+                write_into(sink, Plain(&0xCEu8))?;
+                write_into(sink, BigEndian(value as u32))?;
+                Ok(5)
+            }
+            0x1_0000_0000.. => {
+                write_into(sink, Plain(&0xCFu8))?;
+                write_into(sink, BigEndian(value as u64))?;
+                Ok(9)
+            }
+            -0x80..=-33 => {
+                write_into(sink, Plain(&[0xD0, value as u8]))?;
+                Ok(2)
+            }
+            -0x8000..=-0x81 => {
+                write_into(sink, Plain(&0xD1u8))?;
+                write_into(sink, BigEndian(value as i16))?;
+                Ok(3)
+            }
+            -0x8000_0000..=-0x8001 => {
+                write_into(sink, Plain(&0xD2u8))?;
+                write_into(sink, BigEndian(value as i32))?;
+                Ok(5)
+            }
+            _ => {
+                write_into(sink, Plain(&0xD3u8))?;
+                write_into(sink, BigEndian(value))?;
+                Ok(9)
+            }
+        }
+    }
+}
+
+/// Returns how many bytes was written.
+impl WriteInto for &MsgPackInt {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, MsgPackInt(self.0))
+    }
+}
+
+/// Used to write maps in MessagePack format (map header followed by keys and values).
+///
+/// The header is `fixmap`, `map16` or `map32` depending on the number of entries. Writing a map
+/// with more than [`u32::MAX`] entries fails with [`io::ErrorKind::InvalidInput`]. Keys are
+/// written in their [`Ord`] order, so [`MsgPackInt`] can be used as a key.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::BTreeMap;
+/// use write_into::{MsgPackInt, MsgPackMap, write_into};
+///
+/// let map = BTreeMap::from([(MsgPackInt(1), MsgPackInt(-1))]);
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, MsgPackMap(map)).unwrap();
+/// assert_eq!(written, 1);
+/// assert_eq!(&buffer, &[0x81, 0x01, 0xFF]);
+/// ```
+pub struct MsgPackMap<K, V>(pub BTreeMap<K, V>)
+where
+    K: WriteInto,
+    V: WriteInto;
+
+/// Returns how many entries was written.
+impl<K, V> WriteInto for MsgPackMap<K, V>
+where
+    K: WriteInto,
+    V: WriteInto,
+{
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let written = self.0.len();
+        write_map_header(sink, written)?;
+        for (key, value) in self.0 {
+            write_into(sink, key)?;
+            write_into(sink, value)?;
+        }
+
+        Ok(written)
+    }
+}
+
+/// Returns how many entries was written.
+impl<'a, K, V> WriteInto for &'a MsgPackMap<K, V>
+where
+    K: WriteInto,
+    V: WriteInto,
+    &'a K: WriteInto,
+    &'a V: WriteInto,
+{
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let written = self.0.len();
+        write_map_header(sink, written)?;
+        for (key, value) in &self.0 {
+            write_into(sink, key)?;
+            write_into(sink, value)?;
+        }
+
+        Ok(written)
+    }
+}
+
+fn write_map_header(sink: &mut impl io::Write, entries: usize) -> io::Result<()> {
+    if entries <= 15 {
+        write_into(sink, Plain(&(0x80 | entries as u8)))
+    } else if let Ok(entries) = u16::try_from(entries) {
+        write_into(sink, Plain(&0xDEu8))?;
+        write_into(sink, BigEndian(entries))?;
+        Ok(())
+    } else if let Ok(entries) = u32::try_from(entries) {
+        write_into(sink, Plain(&0xDFu8))?;
+        write_into(sink, BigEndian(entries))?;
+        Ok(())
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "map has more than u32::MAX entries",
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use super::*;
+    use test_case::test_case;
+    use validators::vec;
+
+    mod validators {
+        pub fn vec(expected: &[u8]) -> impl FnOnce(Vec<u8>) {
+            let expected = expected.to_vec();
+            move |actual| assert_eq!(&expected, &actual)
+        }
+    }
+
+    #[test_case(        0 => using vec(&[ 0x00                                                 ]); "when           0" )]
+    #[test_case(      127 => using vec(&[ 0x7F                                                 ]); "when         127" )]
+    #[test_case(      128 => using vec(&[ 0xCC, 0x80                                           ]); "when         128" )]
+    #[test_case(    65535 => using vec(&[ 0xCD, 0xFF, 0xFF                                     ]); "when       65535" )]
+    #[test_case(    65536 => using vec(&[ 0xCE, 0x00, 0x01, 0x00, 0x00                         ]); "when       65536" )]
+    #[test_case( i64::MAX => using vec(&[ 0xCF, 0x7F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF ]); "when     i64 max" )]
+    #[test_case(       -1 => using vec(&[ 0xFF                                                 ]); "when     minus 1" )]
+    #[test_case(      -32 => using vec(&[ 0xE0                                                 ]); "when    minus 32" )]
+    #[test_case(      -33 => using vec(&[ 0xD0, 0xDF                                           ]); "when    minus 33" )]
+    #[test_case(     -129 => using vec(&[ 0xD1, 0xFF, 0x7F                                     ]); "when   minus 129" )]
+    #[test_case(   -32769 => using vec(&[ 0xD2, 0xFF, 0xFF, 0x7F, 0xFF                         ]); "when minus 32769" )]
+    #[test_case( i64::MIN => using vec(&[ 0xD3, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00 ]); "when     i64 min" )]
+    fn write_msgpack_int(value: i64) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, MsgPackInt(value)).unwrap();
+        assert_eq!(written, buffer.len());
+        buffer
+    }
+
+    #[test]
+    fn write_fixmap() {
+        let map = BTreeMap::from([
+            (MsgPackInt(1), MsgPackInt(2)),
+            (MsgPackInt(3), MsgPackInt(4)),
+        ]);
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, &MsgPackMap(map)).unwrap();
+        assert_eq!(written, 2);
+        assert_eq!(&buffer, &[0x82, 0x01, 0x02, 0x03, 0x04]);
+    }
+
+    #[test]
+    fn write_map16() {
+        let map = (0..16).map(|i| (MsgPackInt(i), MsgPackInt(0))).collect();
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, MsgPackMap(map)).unwrap();
+        assert_eq!(written, 16);
+        assert_eq!(&buffer[..5], &[0xDE, 0x00, 0x10, 0x00, 0x00]);
+        assert_eq!(buffer.len(), 3 + 16 * 2);
+    }
+}
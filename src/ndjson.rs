@@ -0,0 +1,82 @@
+use super::{io, write_into, Plain, WriteInto};
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// Used to write records as newline-delimited JSON (NDJSON).
+///
+/// Each record is expected to be already serialized as a single line of JSON and is followed by
+/// `\n`.
+///
+/// # Example
+///
+/// ```
+/// use write_into::{Ndjson, Plain, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, Ndjson(vec![Plain("{}"), Plain("[]")])).unwrap();
+/// assert_eq!(written, 2);
+/// assert_eq!(&buffer, b"{}\n[]\n");
+/// ```
+pub struct Ndjson<T>(pub Vec<T>)
+where
+    T: WriteInto;
+
+/// Returns how many records was written.
+impl<T> WriteInto for Ndjson<T>
+where
+    T: WriteInto,
+{
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let written = self.0.len();
+        for record in self.0 {
+            write_into(sink, record)?;
+            write_into(sink, Plain(&b'\n'))?;
+        }
+
+        Ok(written)
+    }
+}
+
+/// Returns how many records was written.
+impl<'a, T> WriteInto for &'a Ndjson<T>
+where
+    T: WriteInto,
+    &'a T: WriteInto,
+{
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, Ndjson(self.0.iter().collect()))
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::super::*;
+
+    #[test]
+    fn write_records() {
+        let records = Ndjson(vec![
+            Plain(r#"{"level":"info","msg":"started"}"#),
+            Plain(r#"{"level":"warn","msg":"low disk"}"#),
+        ]);
+
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, &records).unwrap();
+        assert_eq!(written, 2);
+        assert_eq!(
+            &buffer,
+            b"{\"level\":\"info\",\"msg\":\"started\"}\n{\"level\":\"warn\",\"msg\":\"low disk\"}\n"
+        );
+    }
+
+    #[test]
+    fn write_no_records() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, Ndjson(Vec::<Plain<&str>>::new())).unwrap();
+        assert_eq!(written, 0);
+        assert!(buffer.is_empty());
+    }
+}
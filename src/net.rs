@@ -0,0 +1,400 @@
+use super::{write_into, BigEndian, Plain, WriteInto};
+use std::io;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+/// Used to write socket addresses in SOCKS5 format (address type, address and big endian port).
+///
+/// # Example
+///
+/// ```
+/// use std::net::SocketAddr;
+/// use write_into::{Socks5Addr, write_into};
+///
+/// let address: SocketAddr = "127.0.0.1:1080".parse().unwrap();
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, Socks5Addr(address)).unwrap();
+/// assert_eq!(written, 7);
+/// assert_eq!(&buffer, &[0x01, 0x7F, 0x00, 0x00, 0x01, 0x04, 0x38]);
+/// ```
+pub struct Socks5Addr(pub SocketAddr);
+
+/// Returns how many bytes was written.
+impl WriteInto for Socks5Addr {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let written = match self.0 {
+            SocketAddr::V4(address) => {
+                write_into(sink, Plain(&0x01u8))?;
+                write_into(sink, Plain(&address.ip().octets()))?;
+                7
+            }
+            SocketAddr::V6(address) => {
+                write_into(sink, Plain(&0x04u8))?;
+                write_into(sink, Plain(&address.ip().octets()))?;
+                19
+            }
+        };
+
+        write_into(sink, BigEndian(self.0.port()))?;
+        Ok(written)
+    }
+}
+
+/// Returns how many bytes was written.
+impl WriteInto for &Socks5Addr {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, Socks5Addr(self.0))
+    }
+}
+
+/// Used to write IP addresses in their canonical text form.
+///
+/// # Example
+///
+/// ```
+/// use std::net::{IpAddr, Ipv6Addr};
+/// use write_into::{IpText, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, IpText(IpAddr::V6(Ipv6Addr::LOCALHOST))).unwrap();
+/// assert_eq!(written, 3);
+/// assert_eq!(&buffer, b"::1");
+/// ```
+pub struct IpText(pub IpAddr);
+
+/// Returns how many bytes was written.
+impl WriteInto for IpText {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let text = self.0.to_string();
+        write_into(sink, Plain(&*text))?;
+        Ok(text.len())
+    }
+}
+
+/// Returns how many bytes was written.
+impl WriteInto for &IpText {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, IpText(self.0))
+    }
+}
+
+/// Used to write domain names as DNS label sequences.
+///
+/// Each dot-separated label is written as a length byte followed by its bytes, and the sequence
+/// is terminated by a zero-length label. A single trailing dot is allowed. Empty labels, labels
+/// longer than 63 bytes and names longer than 255 bytes fail with
+/// [`io::ErrorKind::InvalidInput`].
+///
+/// # Example
+///
+/// ```
+/// use write_into::{DnsName, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, DnsName("example.com")).unwrap();
+/// assert_eq!(written, 13);
+/// assert_eq!(&buffer, b"\x07example\x03com\x00");
+/// ```
+pub struct DnsName<'a>(pub &'a str);
+
+/// Returns how many bytes was written.
+impl WriteInto for DnsName<'_> {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let name = self.0.strip_suffix('.').unwrap_or(self.0);
+        let labels = name.split('.').filter(|_| !name.is_empty());
+
+        let mut written = 1;
+        for label in labels.clone() {
+            if label.is_empty() {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "empty label in domain name",
+                ));
+            }
+
+            if label.len() > 63 {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "label is longer than 63 bytes",
+                ));
+            }
+
+            written += 1 + label.len();
+        }
+
+        if written > 255 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "domain name is longer than 255 bytes",
+            ));
+        }
+
+        for label in labels {
+            write_into(sink, Plain(&(label.len() as u8)))?;
+            write_into(sink, Plain(label))?;
+        }
+
+        write_into(sink, Plain(&0u8))?;
+        Ok(written)
+    }
+}
+
+/// Returns how many bytes was written.
+impl WriteInto for &DnsName<'_> {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, DnsName(self.0))
+    }
+}
+
+macro_rules! impl_write_into_octets {
+    ($($address:ident)*) => {
+        $(
+            /// Writes the octets of the address in network byte order.
+            impl WriteInto for $address {
+                type Output = ();
+
+                fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+                    write_into(sink, Plain(&self.octets()))
+                }
+            }
+
+            /// Writes the octets of the address in network byte order.
+            impl WriteInto for &$address {
+                type Output = ();
+
+                fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+                    write_into(sink, *self)
+                }
+            }
+        )*
+    };
+}
+
+impl_write_into_octets! {
+    Ipv4Addr Ipv6Addr
+}
+
+macro_rules! impl_write_into_socket {
+    ($($address:ident)*) => {
+        $(
+            /// Writes the octets of the address followed by the big endian port.
+            impl WriteInto for $address {
+                type Output = ();
+
+                fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+                    write_into(sink, self.ip())?;
+                    write_into(sink, BigEndian(self.port()))?;
+                    Ok(())
+                }
+            }
+
+            /// Writes the octets of the address followed by the big endian port.
+            impl WriteInto for &$address {
+                type Output = ();
+
+                fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+                    write_into(sink, *self)
+                }
+            }
+        )*
+    };
+}
+
+impl_write_into_socket! {
+    SocketAddrV4 SocketAddrV6
+}
+
+/// Writes the octets of the address in network byte order (4 or 16 bytes, without a tag).
+impl WriteInto for IpAddr {
+    type Output = ();
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        match self {
+            IpAddr::V4(address) => write_into(sink, address),
+            IpAddr::V6(address) => write_into(sink, address),
+        }
+    }
+}
+
+/// Writes the octets of the address in network byte order (4 or 16 bytes, without a tag).
+impl WriteInto for &IpAddr {
+    type Output = ();
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, *self)
+    }
+}
+
+/// Writes the octets of the address followed by the big endian port (without a tag).
+impl WriteInto for SocketAddr {
+    type Output = ();
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        match self {
+            SocketAddr::V4(address) => write_into(sink, address),
+            SocketAddr::V6(address) => write_into(sink, address),
+        }
+    }
+}
+
+/// Writes the octets of the address followed by the big endian port (without a tag).
+impl WriteInto for &SocketAddr {
+    type Output = ();
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, *self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use super::*;
+
+    #[test]
+    fn write_socks5_ipv4() {
+        let address: SocketAddr = "192.168.1.10:8080".parse().unwrap();
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, Socks5Addr(address)).unwrap();
+        assert_eq!(written, 7);
+        assert_eq!(&buffer, &[0x01, 0xC0, 0xA8, 0x01, 0x0A, 0x1F, 0x90]);
+    }
+
+    #[test]
+    fn write_socks5_ipv6() {
+        let address: SocketAddr = "[2001:db8::1]:443".parse().unwrap();
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, Socks5Addr(address)).unwrap();
+        assert_eq!(written, 19);
+        assert_eq!(
+            &buffer,
+            &[
+                0x04, 0x20, 0x01, 0x0D, 0xB8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x01, 0x01, 0xBB
+            ]
+        );
+    }
+
+    #[test]
+    fn write_ipv4_text() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, IpText("127.0.0.1".parse().unwrap())).unwrap();
+        assert_eq!(written, 9);
+        assert_eq!(&buffer, b"127.0.0.1");
+    }
+
+    #[test]
+    fn write_ipv6_text() {
+        let address = "2001:0db8:0000:0000:0000:0000:0000:0001".parse().unwrap();
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, IpText(address)).unwrap();
+        assert_eq!(written, 11);
+        assert_eq!(&buffer, b"2001:db8::1");
+    }
+
+    #[test]
+    fn write_dns_name() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, DnsName("www.example.com.")).unwrap();
+        assert_eq!(written, 17);
+        assert_eq!(&buffer, b"\x03www\x07example\x03com\x00");
+    }
+
+    #[test]
+    fn write_dns_root() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, DnsName(".")).unwrap();
+        assert_eq!(written, 1);
+        assert_eq!(&buffer, &[0x00]);
+    }
+
+    #[test]
+    fn write_dns_name_with_long_label() {
+        let name = format!("{}.com", "a".repeat(64));
+        let mut buffer = Vec::new();
+        let error = write_into(&mut buffer, DnsName(&name)).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn write_dns_name_too_long() {
+        let name = vec!["a".repeat(63); 4].join(".");
+        let mut buffer = Vec::new();
+        let error = write_into(&mut buffer, DnsName(&name)).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn write_dns_name_with_empty_label() {
+        let mut buffer = Vec::new();
+        let error = write_into(&mut buffer, DnsName("example..com")).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn write_ipv4_octets() {
+        let mut buffer = Vec::new();
+        write_into(&mut buffer, Ipv4Addr::new(192, 168, 1, 10)).unwrap();
+        assert_eq!(&buffer, &[192, 168, 1, 10]);
+    }
+
+    #[test]
+    fn write_ipv6_octets() {
+        let address: Ipv6Addr = "2001:db8::1".parse().unwrap();
+        let mut buffer = Vec::new();
+        write_into(&mut buffer, &address).unwrap();
+        assert_eq!(&buffer, &address.octets());
+    }
+
+    #[test]
+    fn write_socket_v4() {
+        let address: SocketAddrV4 = "127.0.0.1:80".parse().unwrap();
+        let mut buffer = Vec::new();
+        write_into(&mut buffer, address).unwrap();
+        assert_eq!(&buffer, &[127, 0, 0, 1, 0, 80]);
+    }
+
+    #[test]
+    fn write_socket_v6() {
+        let address: SocketAddrV6 = "[::1]:443".parse().unwrap();
+        let mut buffer = Vec::new();
+        write_into(&mut buffer, &address).unwrap();
+        assert_eq!(&buffer[..16], &Ipv6Addr::LOCALHOST.octets());
+        assert_eq!(&buffer[16..], &[0x01, 0xBB]);
+    }
+
+    #[test]
+    fn write_ip_address() {
+        let mut buffer = Vec::new();
+        write_into(&mut buffer, IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1))).unwrap();
+        write_into(&mut buffer, &IpAddr::V6(Ipv6Addr::LOCALHOST)).unwrap();
+        assert_eq!(&buffer[..4], &[10, 0, 0, 1]);
+        assert_eq!(&buffer[4..], &Ipv6Addr::LOCALHOST.octets());
+    }
+
+    #[test]
+    fn write_socket_address() {
+        let v4: SocketAddr = "127.0.0.1:80".parse().unwrap();
+        let v6: SocketAddr = "[::1]:443".parse().unwrap();
+        let mut buffer = Vec::new();
+        write_into(&mut buffer, v4).unwrap();
+        write_into(&mut buffer, &v6).unwrap();
+        assert_eq!(&buffer[..6], &[127, 0, 0, 1, 0, 80]);
+        assert_eq!(&buffer[6..22], &Ipv6Addr::LOCALHOST.octets());
+        assert_eq!(&buffer[22..], &[0x01, 0xBB]);
+    }
+}
@@ -0,0 +1,85 @@
+use super::{write_into, BigEndian, WriteInto};
+use std::io;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Seconds between the NTP epoch _(1900-01-01)_ and the Unix epoch _(1970-01-01)_.
+const UNIX_EPOCH_OFFSET: i64 = 2_208_988_800;
+
+/// Used to write time points in the 64-bit NTP timestamp format.
+///
+/// The timestamp consists of 32-bit seconds since 1900-01-01 followed by 32-bit fractional
+/// seconds, both in big endian byte order. Seconds wrap around at the end of each NTP era, so
+/// times after 2036-02-07 are written as in the next era.
+///
+/// # Example
+///
+/// ```
+/// use std::time::{Duration, UNIX_EPOCH};
+/// use write_into::{NtpTimestamp, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let time = UNIX_EPOCH + Duration::from_millis(500);
+/// write_into(&mut buffer, NtpTimestamp(time)).unwrap();
+/// assert_eq!(&buffer, &[0x83, 0xAA, 0x7E, 0x80, 0x80, 0x00, 0x00, 0x00]);
+/// ```
+pub struct NtpTimestamp(pub SystemTime);
+
+impl WriteInto for NtpTimestamp {
+    type Output = ();
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let (seconds, nanos) = match self.0.duration_since(UNIX_EPOCH) {
+            Ok(since) => (since.as_secs() as i64, since.subsec_nanos()),
+            Err(error) => {
+                let before = error.duration();
+                match before.subsec_nanos() {
+                    0 => (-(before.as_secs() as i64), 0),
+                    nanos => (-(before.as_secs() as i64) - 1, 1_000_000_000 - nanos),
+                }
+            }
+        };
+
+        let seconds = seconds.wrapping_add(UNIX_EPOCH_OFFSET) as u32;
+        let fraction = ((nanos as u64) << 32) / 1_000_000_000;
+        write_into(sink, BigEndian(seconds))?;
+        write_into(sink, BigEndian(fraction as u32))?;
+        Ok(())
+    }
+}
+
+impl WriteInto for &NtpTimestamp {
+    type Output = ();
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, NtpTimestamp(self.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    #[test]
+    fn write_unix_epoch() {
+        let mut buffer = Vec::new();
+        write_into(&mut buffer, NtpTimestamp(UNIX_EPOCH)).unwrap();
+        assert_eq!(&buffer, &[0x83, 0xAA, 0x7E, 0x80, 0x00, 0x00, 0x00, 0x00]);
+    }
+
+    #[test]
+    fn write_year_2000() {
+        let mut buffer = Vec::new();
+        let time = UNIX_EPOCH + Duration::new(946_684_800, 250_000_000);
+        write_into(&mut buffer, NtpTimestamp(time)).unwrap();
+        assert_eq!(&buffer, &[0xBC, 0x17, 0xC2, 0x00, 0x40, 0x00, 0x00, 0x00]);
+    }
+
+    #[test]
+    fn write_before_unix_epoch() {
+        let mut buffer = Vec::new();
+        let time = UNIX_EPOCH - Duration::from_millis(250);
+        write_into(&mut buffer, NtpTimestamp(time)).unwrap();
+        assert_eq!(&buffer, &[0x83, 0xAA, 0x7E, 0x7F, 0xC0, 0x00, 0x00, 0x00]);
+    }
+}
@@ -0,0 +1,105 @@
+use super::{write_into, Plain, WriteInto};
+use std::io;
+
+/// Used to write strings and byte slices followed by a zero byte (as C strings).
+///
+/// Writing a value containing an interior zero byte fails with [`io::ErrorKind::InvalidInput`]
+/// without writing anything.
+///
+/// # Example
+///
+/// ```
+/// use write_into::{NullTerminated, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, NullTerminated(".text")).unwrap();
+/// assert_eq!(written, 6);
+/// assert_eq!(&buffer, b".text\0");
+/// ```
+pub struct NullTerminated<T>(pub T);
+
+/// Returns how many bytes was written (including the terminator).
+impl WriteInto for NullTerminated<&[u8]> {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        if self.0.contains(&0) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "value contains an interior zero byte",
+            ));
+        }
+
+        write_into(sink, Plain(self.0))?;
+        write_into(sink, Plain(&0u8))?;
+        Ok(self.0.len() + 1)
+    }
+}
+
+/// Returns how many bytes was written (including the terminator).
+impl WriteInto for &NullTerminated<&[u8]> {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, NullTerminated(self.0))
+    }
+}
+
+/// Returns how many bytes was written (including the terminator).
+impl WriteInto for NullTerminated<&str> {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, NullTerminated(self.0.as_bytes()))
+    }
+}
+
+/// Returns how many bytes was written (including the terminator).
+impl WriteInto for &NullTerminated<&str> {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, NullTerminated(self.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+
+    #[test]
+    fn write_string_table() {
+        let mut buffer = vec![0];
+        let mut written = 0;
+        for name in [".text", ".data"] {
+            written += write_into(&mut buffer, NullTerminated(name)).unwrap();
+        }
+
+        assert_eq!(written, 12);
+        assert_eq!(&buffer, b"\0.text\0.data\0");
+    }
+
+    #[test]
+    fn write_empty_string() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, &NullTerminated("")).unwrap();
+        assert_eq!(written, 1);
+        assert_eq!(&buffer, &[0x00]);
+    }
+
+    #[test]
+    fn write_bytes() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, NullTerminated(&[0xCA, 0xFE][..])).unwrap();
+        assert_eq!(written, 3);
+        assert_eq!(&buffer, &[0xCA, 0xFE, 0x00]);
+    }
+
+    #[test]
+    fn write_interior_null() {
+        let mut buffer = Vec::new();
+        let error = write_into(&mut buffer, NullTerminated("a\0b")).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert!(buffer.is_empty());
+    }
+}
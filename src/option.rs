@@ -0,0 +1,55 @@
+use super::{io, write_into, WriteInto};
+
+/// Writes the value if it is present, writes nothing otherwise.
+impl<T> WriteInto for Option<T>
+where
+    T: WriteInto,
+{
+    type Output = Option<T::Output>;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        self.map(|value| write_into(sink, value)).transpose()
+    }
+}
+
+/// Writes the value if it is present, writes nothing otherwise.
+impl<'a, T> WriteInto for &'a Option<T>
+where
+    &'a T: WriteInto,
+{
+    type Output = Option<<&'a T as WriteInto>::Output>;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, self.as_ref())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::super::*;
+
+    #[test]
+    fn write_some() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, Some(BigEndian(0xCAFEu16))).unwrap();
+        assert_eq!(written, Some(2));
+        assert_eq!(&buffer, &[0xCA, 0xFE]);
+    }
+
+    #[test]
+    fn write_none() {
+        let mut sink = CountingWriter::new(Vec::new());
+        let written = write_into(&mut sink, None::<BigEndian<u16>>).unwrap();
+        assert_eq!(written, None);
+        assert_eq!(sink.count(), 0);
+        assert!(sink.into_inner().is_empty());
+    }
+
+    #[test]
+    fn write_optional_fields() {
+        let header = (Plain("id"), Some(Plain("=1")), None::<Plain<&str>>);
+        let mut buffer = Vec::new();
+        write_into(&mut buffer, &header).unwrap();
+        assert_eq!(&buffer, b"id=1");
+    }
+}
@@ -0,0 +1,214 @@
+use super::{write_into, Plain, WriteInto};
+use std::io::{self, Read};
+
+/// Used to write values padded to the next power of two size.
+///
+/// The second field is a byte used for padding. Empty representation is padded to a single byte.
+///
+/// # Example
+///
+/// ```
+/// use write_into::{PadToPow2, Plain, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, PadToPow2(Plain("Hello"), 0xFF)).unwrap();
+/// assert_eq!(written, 8);
+/// assert_eq!(&buffer, b"Hello\xFF\xFF\xFF");
+/// ```
+pub struct PadToPow2<T>(pub T, pub u8)
+where
+    T: WriteInto;
+
+/// Returns how many bytes was written (including the padding).
+impl<T> WriteInto for PadToPow2<T>
+where
+    T: WriteInto,
+{
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let mut buffer = Vec::new();
+        write_into(&mut buffer, self.0)?;
+        let size = buffer.len().next_power_of_two();
+        buffer.resize(size, self.1);
+
+        write_into(sink, Plain(&buffer[..]))?;
+        Ok(size)
+    }
+}
+
+/// Returns how many bytes was written (including the padding).
+impl<T> WriteInto for &PadToPow2<T>
+where
+    T: Copy + WriteInto,
+{
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, PadToPow2(self.0, self.1))
+    }
+}
+
+/// Used to write byte slices padded with zeros to a fixed length.
+///
+/// The second field is the length of the field. Writing a slice longer than the field fails with
+/// [`io::ErrorKind::InvalidInput`].
+///
+/// # Example
+///
+/// ```
+/// use write_into::{NullPaddedField, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, NullPaddedField(b"ustar", 8)).unwrap();
+/// assert_eq!(written, 8);
+/// assert_eq!(&buffer, b"ustar\0\0\0");
+/// ```
+pub struct NullPaddedField<'a>(pub &'a [u8], pub usize);
+
+/// Returns how many bytes was written (the length of the field).
+impl WriteInto for NullPaddedField<'_> {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let padding = self.1.checked_sub(self.0.len()).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "slice is longer than the field",
+            )
+        })?;
+
+        write_into(sink, Plain(self.0))?;
+        io::copy(&mut io::repeat(0).take(padding as u64), sink)?;
+        Ok(self.1)
+    }
+}
+
+/// Returns how many bytes was written (the length of the field).
+impl WriteInto for &NullPaddedField<'_> {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, NullPaddedField(self.0, self.1))
+    }
+}
+
+/// Used to write values padded to a fixed length.
+///
+/// The second field is the length and the third one is a byte used for padding. Writing a value
+/// with representation longer than the length fails with [`io::ErrorKind::InvalidInput`].
+///
+/// # Example
+///
+/// ```
+/// use write_into::{PadTo, Plain, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, PadTo(Plain("hi"), 4, 0x00)).unwrap();
+/// assert_eq!(written, 4);
+/// assert_eq!(&buffer, b"hi\0\0");
+/// ```
+pub struct PadTo<T>(pub T, pub usize, pub u8)
+where
+    T: WriteInto;
+
+/// Returns how many bytes was written (the length).
+impl<T> WriteInto for PadTo<T>
+where
+    T: WriteInto,
+{
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let mut buffer = Vec::new();
+        write_into(&mut buffer, self.0)?;
+        if buffer.len() > self.1 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "representation is longer than the length",
+            ));
+        }
+
+        buffer.resize(self.1, self.2);
+        write_into(sink, Plain(&buffer[..]))?;
+        Ok(self.1)
+    }
+}
+
+/// Returns how many bytes was written (the length).
+impl<T> WriteInto for &PadTo<T>
+where
+    T: Copy + WriteInto,
+{
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, PadTo(self.0, self.1, self.2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+
+    #[test]
+    fn pad_to_pow2_when_padding_needed() {
+        let mut buffer = Vec::new();
+        let written =
+            write_into(&mut buffer, PadToPow2(Plain(b"\x01\x02\x03\x04\x05"), 0x00)).unwrap();
+        assert_eq!(written, 8);
+        assert_eq!(&buffer, &[0x01, 0x02, 0x03, 0x04, 0x05, 0x00, 0x00, 0x00]);
+    }
+
+    #[test]
+    fn pad_to_pow2_when_already_pow2() {
+        let mut buffer = Vec::new();
+        let written = write_into(
+            &mut buffer,
+            PadToPow2(BigEndian(0x0102030405060708u64), 0x00),
+        )
+        .unwrap();
+        assert_eq!(written, 8);
+        assert_eq!(&buffer, &[0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08]);
+    }
+
+    #[test]
+    fn null_padded_field_when_shorter() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, NullPaddedField(b"abc", 6)).unwrap();
+        assert_eq!(written, 6);
+        assert_eq!(&buffer, b"abc\0\0\0");
+    }
+
+    #[test]
+    fn null_padded_field_when_longer() {
+        let mut buffer = Vec::new();
+        let error = write_into(&mut buffer, NullPaddedField(b"abcdefg", 6)).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn pad_to_when_shorter() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, PadTo(Plain("hi"), 4, 0x00)).unwrap();
+        assert_eq!(written, 4);
+        assert_eq!(&buffer, b"hi\0\0");
+    }
+
+    #[test]
+    fn pad_to_when_exact() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, PadTo(BigEndian(0xAABBu16), 2, 0xFF)).unwrap();
+        assert_eq!(written, 2);
+        assert_eq!(&buffer, &[0xAA, 0xBB]);
+    }
+
+    #[test]
+    fn pad_to_when_longer() {
+        let mut buffer = Vec::new();
+        let error = write_into(&mut buffer, PadTo(Plain("hello"), 4, 0x00)).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert!(buffer.is_empty());
+    }
+}
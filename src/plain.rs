@@ -1,7 +1,6 @@
-use super::{write_into, WriteInto};
-use std::io;
-use std::mem::size_of;
-use std::slice::from_raw_parts;
+use super::{io, write_into, WriteInto};
+use core::mem::{size_of, size_of_val};
+use core::slice::from_raw_parts;
 
 /// Used to write values as they are represented in memory.
 ///
@@ -9,7 +8,8 @@ use std::slice::from_raw_parts;
 ///
 /// Writing struct into a sink.
 ///
-/// ```
+#[cfg_attr(feature = "alloc", doc = "```")]
+#[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
 /// use write_into::{Plain, write_into};
 ///
 /// struct Rgba {
@@ -27,7 +27,8 @@ use std::slice::from_raw_parts;
 ///
 /// Writing array into a sink.
 ///
-/// ```
+#[cfg_attr(feature = "alloc", doc = "```")]
+#[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
 /// use write_into::{Plain, write_into};
 ///
 /// let bytes: &[u8; 4] = b"\0asm";
@@ -38,7 +39,8 @@ use std::slice::from_raw_parts;
 ///
 /// Writing slice into a sink (the crate also provides implementation for [`Plain<&str>`]).
 ///
-/// ```
+#[cfg_attr(feature = "alloc", doc = "```")]
+#[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
 /// use write_into::{Plain, write_into};
 ///
 /// let bytes: &[u8] = b"([Ljava/lang/String;)V";
@@ -46,12 +48,14 @@ use std::slice::from_raw_parts;
 /// write_into(&mut buffer, Plain(bytes)).unwrap();
 /// assert_eq!(&buffer, b"([Ljava/lang/String;)V");
 /// ```
+#[derive(Clone, Copy)]
 pub struct Plain<T>(pub T);
 
 /// Transmutes arbitrary value into a byte slice.
 impl<T> WriteInto for Plain<&T> {
     type Output = ();
 
+    #[allow(clippy::needless_borrow)]
     fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
         // SAFETY:
         // - The slice points to a memory occupied by the data.
@@ -79,15 +83,7 @@ impl<T> WriteInto for Plain<&[T]> {
     type Output = ();
 
     fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
-        // SAFETY:
-        // - The slice points to a memory occupied by the data.
-        // - The data is immutably borrowed.
-        let bytes = unsafe {
-            let data = self.0 as *const [T] as *const u8;
-            from_raw_parts(data, self.0.len() * size_of::<T>())
-        };
-
-        sink.write_all(&bytes)?;
+        sink.write_all(slice_as_bytes(self.0))?;
         Ok(())
     }
 }
@@ -145,7 +141,18 @@ impl_write_into! {
     bool char f32 f64
 }
 
-#[cfg(test)]
+/// Reinterprets arbitrary slice as a byte slice.
+pub(crate) fn slice_as_bytes<T>(slice: &[T]) -> &[u8] {
+    // SAFETY:
+    // - The slice points to a memory occupied by the data.
+    // - The data is immutably borrowed.
+    unsafe {
+        let data = slice as *const [T] as *const u8;
+        from_raw_parts(data, size_of_val(slice))
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use super::super::*;
     use super::*;
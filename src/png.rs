@@ -0,0 +1,83 @@
+use super::checksum::Crc32;
+use super::{write_into, BigEndian, Plain, WriteInto};
+use std::io;
+
+/// Used to write PNG chunks (length, type, data and CRC-32 of the type and the data).
+///
+/// The first field is the chunk type. Writing data longer than `2^31 - 1` bytes fails with
+/// [`io::ErrorKind::InvalidInput`].
+///
+/// # Example
+///
+/// ```
+/// use write_into::{PngChunk, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, PngChunk(*b"IEND", &[])).unwrap();
+/// assert_eq!(written, 12);
+/// assert_eq!(&buffer, b"\0\0\0\0IEND\xAE\x42\x60\x82");
+/// ```
+pub struct PngChunk<'a>(pub [u8; 4], pub &'a [u8]);
+
+/// Returns how many bytes was written.
+impl WriteInto for PngChunk<'_> {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let length = u32::try_from(self.1.len())
+            .ok()
+            .filter(|&length| length <= i32::MAX as u32)
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "chunk data is longer than 2^31 - 1 bytes",
+                )
+            })?;
+
+        let mut crc = Crc32::new();
+        crc.update(&self.0);
+        crc.update(self.1);
+
+        write_into(sink, BigEndian(length))?;
+        write_into(sink, Plain(&self.0))?;
+        write_into(sink, Plain(self.1))?;
+        write_into(sink, BigEndian(crc.finish()))?;
+        Ok(12 + self.1.len())
+    }
+}
+
+/// Returns how many bytes was written.
+impl WriteInto for &PngChunk<'_> {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, PngChunk(self.0, self.1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+
+    #[test]
+    fn write_iend() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, PngChunk(*b"IEND", &[])).unwrap();
+        assert_eq!(written, 12);
+        assert_eq!(
+            &buffer,
+            &[0x00, 0x00, 0x00, 0x00, 0x49, 0x45, 0x4E, 0x44, 0xAE, 0x42, 0x60, 0x82]
+        );
+    }
+
+    #[test]
+    fn write_ihdr() {
+        // 1x1 8-bit grayscale image.
+        let data = [0, 0, 0, 1, 0, 0, 0, 1, 8, 0, 0, 0, 0];
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, PngChunk(*b"IHDR", &data)).unwrap();
+        assert_eq!(written, 25);
+        assert_eq!(&buffer[..8], b"\0\0\0\x0DIHDR");
+        assert_eq!(&buffer[21..], &[0x3A, 0x7E, 0x9B, 0x55]);
+    }
+}
@@ -0,0 +1,105 @@
+use super::{io, write_into, Plain, Uleb128, WriteInto};
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// Used to write integers from [`IntoIterator`] as a Protobuf packed repeated field.
+///
+/// The first field is the field number. The field key (with wire type 2) and the size of the
+/// packed values are written before the values, all in LEB-128 format _(unsigned)_. Writing a field
+/// number outside of `1..=2^29 - 1` fails with [`io::ErrorKind::InvalidInput`].
+///
+/// # Example
+///
+/// ```
+/// use write_into::{ProtoPackedField, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, ProtoPackedField(4, [3u32, 270, 86942])).unwrap();
+/// assert_eq!(written, 3);
+/// assert_eq!(&buffer, &[0x22, 0x06, 0x03, 0x8E, 0x02, 0x9E, 0xA7, 0x05]);
+/// ```
+pub struct ProtoPackedField<T>(pub u32, pub T)
+where
+    T: IntoIterator,
+    T::Item: Into<u64>;
+
+/// Returns how many items was written.
+impl<T> WriteInto for ProtoPackedField<T>
+where
+    T: IntoIterator,
+    T::Item: Into<u64>,
+{
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        if !(1..1 << 29).contains(&self.0) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "field number is out of range",
+            ));
+        }
+
+        let mut buffer = Vec::new();
+        let mut written = 0;
+        for item in self.1 {
+            write_into(&mut buffer, Uleb128(item.into()))?;
+            written += 1;
+        }
+
+        write_into(sink, Uleb128(self.0 << 3 | 2))?;
+        write_into(sink, Uleb128(buffer.len()))?;
+        write_into(sink, Plain(&buffer[..]))?;
+        Ok(written)
+    }
+}
+
+/// Returns how many items was written.
+impl<T> WriteInto for &ProtoPackedField<T>
+where
+    T: Copy + IntoIterator,
+    T::Item: Into<u64>,
+{
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, ProtoPackedField(self.0, self.1))
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::super::*;
+
+    #[test]
+    fn write_packed_field() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, ProtoPackedField(1, vec![1u64, 150, 3])).unwrap();
+        assert_eq!(written, 3);
+        assert_eq!(&buffer, &[0x0A, 0x04, 0x01, 0x96, 0x01, 0x03]);
+    }
+
+    #[test]
+    fn write_empty_packed_field() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, ProtoPackedField(2, Vec::<u8>::new())).unwrap();
+        assert_eq!(written, 0);
+        assert_eq!(&buffer, &[0x12, 0x00]);
+    }
+
+    #[test]
+    fn write_packed_field_with_invalid_number() {
+        for number in [0, 1 << 29, u32::MAX] {
+            let mut buffer = Vec::new();
+            let error = write_into(&mut buffer, ProtoPackedField(number, [1u8])).unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+            assert!(buffer.is_empty());
+        }
+    }
+
+    #[test]
+    fn write_packed_field_with_max_number() {
+        let mut buffer = Vec::new();
+        write_into(&mut buffer, ProtoPackedField((1 << 29) - 1, [1u8])).unwrap();
+        assert_eq!(&buffer, &[0xFA, 0xFF, 0xFF, 0xFF, 0x0F, 0x01, 0x01]);
+    }
+}
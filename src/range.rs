@@ -0,0 +1,74 @@
+use super::{write_into, BigEndian, LittleEndian, WriteInto};
+use std::io;
+use std::ops::Range;
+
+/// Used to write ranges as their start followed by their length.
+///
+/// The range is wrapped into [`BigEndian`] or [`LittleEndian`], which determines the byte order
+/// of the [`u32`] start and length. Writing a range whose end precedes its start fails with
+/// [`io::ErrorKind::InvalidInput`].
+///
+/// # Example
+///
+/// ```
+/// use write_into::{BigEndian, OffsetLength, write_into};
+///
+/// let mut buffer = Vec::new();
+/// write_into(&mut buffer, OffsetLength(BigEndian(4..10))).unwrap();
+/// assert_eq!(&buffer, &[0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x06]);
+/// ```
+pub struct OffsetLength<E>(pub E);
+
+macro_rules! impl_write_into {
+    ($($wrapper:ident)*) => {
+        $(
+            impl WriteInto for OffsetLength<$wrapper<Range<u32>>> {
+                type Output = ();
+
+                fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+                    let Range { start, end } = (self.0).0;
+                    let length = end.checked_sub(start).ok_or_else(|| {
+                        io::Error::new(io::ErrorKind::InvalidInput, "range end precedes its start")
+                    })?;
+
+                    write_into(sink, $wrapper(start))?;
+                    write_into(sink, $wrapper(length))?;
+                    Ok(())
+                }
+            }
+
+            impl WriteInto for &OffsetLength<$wrapper<Range<u32>>> {
+                type Output = ();
+
+                fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+                    write_into(sink, OffsetLength($wrapper((self.0).0.clone())))
+                }
+            }
+        )*
+    };
+}
+
+impl_write_into! {
+    BigEndian LittleEndian
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+
+    #[test]
+    fn write_range_le() {
+        let mut buffer = Vec::new();
+        write_into(&mut buffer, &OffsetLength(LittleEndian(4..10))).unwrap();
+        assert_eq!(&buffer, &[0x04, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00]);
+    }
+
+    #[test]
+    fn write_inverted_range() {
+        let mut buffer = Vec::new();
+        #[allow(clippy::reversed_empty_ranges)]
+        let error = write_into(&mut buffer, OffsetLength(BigEndian(10..4))).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert!(buffer.is_empty());
+    }
+}
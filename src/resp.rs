@@ -0,0 +1,171 @@
+use super::{write_into, Plain, WriteInto};
+use std::io;
+
+/// Used to write byte slices as RESP _(Redis serialization protocol)_ bulk strings.
+///
+/// # Example
+///
+/// ```
+/// use write_into::{RespBulkString, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, RespBulkString(b"hello")).unwrap();
+/// assert_eq!(written, 11);
+/// assert_eq!(&buffer, b"$5\r\nhello\r\n");
+/// ```
+pub struct RespBulkString<'a>(pub &'a [u8]);
+
+/// Returns how many bytes was written.
+impl WriteInto for RespBulkString<'_> {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let header = format!("${}\r\n", self.0.len());
+        write_into(sink, Plain(&*header))?;
+        write_into(sink, Plain(self.0))?;
+        write_into(sink, Plain("\r\n"))?;
+        Ok(header.len() + self.0.len() + 2)
+    }
+}
+
+/// Returns how many bytes was written.
+impl WriteInto for &RespBulkString<'_> {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, RespBulkString(self.0))
+    }
+}
+
+/// Used to write the RESP null bulk string.
+///
+/// # Example
+///
+/// ```
+/// use write_into::{RespNull, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, RespNull).unwrap();
+/// assert_eq!(written, 5);
+/// assert_eq!(&buffer, b"$-1\r\n");
+/// ```
+pub struct RespNull;
+
+/// Returns how many bytes was written.
+impl WriteInto for RespNull {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, Plain("$-1\r\n"))?;
+        Ok(5)
+    }
+}
+
+/// Returns how many bytes was written.
+impl WriteInto for &RespNull {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, RespNull)
+    }
+}
+
+/// Used to write vectors of RESP values as RESP arrays.
+///
+/// Each element is expected to write a single RESP value and return how many bytes it wrote.
+///
+/// # Example
+///
+/// ```
+/// use write_into::{RespArray, RespBulkString, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, RespArray(vec![
+///     RespBulkString(b"GET"),
+///     RespBulkString(b"key"),
+/// ])).unwrap();
+/// assert_eq!(written, 22);
+/// assert_eq!(&buffer, b"*2\r\n$3\r\nGET\r\n$3\r\nkey\r\n");
+/// ```
+pub struct RespArray<T>(pub Vec<T>)
+where
+    T: WriteInto<Output = usize>;
+
+/// Returns how many bytes was written.
+impl<T> WriteInto for RespArray<T>
+where
+    T: WriteInto<Output = usize>,
+{
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let header = format!("*{}\r\n", self.0.len());
+        write_into(sink, Plain(&*header))?;
+        let mut written = header.len();
+        for element in self.0 {
+            written += write_into(sink, element)?;
+        }
+
+        Ok(written)
+    }
+}
+
+/// Returns how many bytes was written.
+impl<'a, T> WriteInto for &'a RespArray<T>
+where
+    T: WriteInto<Output = usize>,
+    &'a T: WriteInto<Output = usize>,
+{
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, RespArray(self.0.iter().collect()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+
+    #[test]
+    fn write_bulk_string() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, RespBulkString(b"foobar")).unwrap();
+        assert_eq!(written, 12);
+        assert_eq!(&buffer, b"$6\r\nfoobar\r\n");
+    }
+
+    #[test]
+    fn write_empty_bulk_string() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, RespBulkString(b"")).unwrap();
+        assert_eq!(written, 6);
+        assert_eq!(&buffer, b"$0\r\n\r\n");
+    }
+
+    #[test]
+    fn write_null() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, &RespNull).unwrap();
+        assert_eq!(written, 5);
+        assert_eq!(&buffer, b"$-1\r\n");
+    }
+
+    #[test]
+    fn write_array() {
+        let array = RespArray(vec![RespBulkString(b"hello"), RespBulkString(b"world")]);
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, &array).unwrap();
+        assert_eq!(written, 26);
+        assert_eq!(&buffer, b"*2\r\n$5\r\nhello\r\n$5\r\nworld\r\n");
+    }
+
+    #[test]
+    fn write_nested_array() {
+        let array = RespArray(vec![RespArray(vec![RespNull]), RespArray(vec![])]);
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, array).unwrap();
+        assert_eq!(written, 17);
+        assert_eq!(&buffer, b"*2\r\n*1\r\n$-1\r\n*0\r\n");
+    }
+}
@@ -0,0 +1,61 @@
+use super::{io, write_into, Plain, WriteInto};
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// Used to write values with the bytes of their representation in reverse order.
+///
+/// # Example
+///
+/// ```
+/// use write_into::{FullyReversed, Plain, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, FullyReversed(Plain("stressed"))).unwrap();
+/// assert_eq!(written, 8);
+/// assert_eq!(&buffer, b"desserts");
+/// ```
+pub struct FullyReversed<T>(pub T)
+where
+    T: WriteInto;
+
+/// Returns how many bytes was written.
+impl<T> WriteInto for FullyReversed<T>
+where
+    T: WriteInto,
+{
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let mut buffer = Vec::new();
+        write_into(&mut buffer, self.0)?;
+        buffer.reverse();
+
+        write_into(sink, Plain(&buffer[..]))?;
+        Ok(buffer.len())
+    }
+}
+
+/// Returns how many bytes was written.
+impl<T> WriteInto for &FullyReversed<T>
+where
+    T: Copy + WriteInto,
+{
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, FullyReversed(self.0))
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::super::*;
+
+    #[test]
+    fn write_reversed_be() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, FullyReversed(BigEndian(0x0102u16))).unwrap();
+        assert_eq!(written, 2);
+        assert_eq!(&buffer, &[0x02, 0x01]);
+    }
+}
@@ -0,0 +1,78 @@
+use super::{write_into, Uleb128, WriteInto};
+use std::io;
+
+/// Used to write boolean slices as lengths of alternating runs in LEB-128 format _(unsigned)_.
+///
+/// The first run is a run of `false`, so a slice starting with `true` begins with a zero-length
+/// run. An empty slice produces no runs.
+///
+/// # Example
+///
+/// ```
+/// use write_into::{RleBitmap, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, RleBitmap(&[true, true, false])).unwrap();
+/// assert_eq!(written, 3);
+/// assert_eq!(&buffer, &[0x00, 0x02, 0x01]);
+/// ```
+pub struct RleBitmap<'a>(pub &'a [bool]);
+
+/// Returns how many runs was written.
+impl WriteInto for RleBitmap<'_> {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let mut written = 0;
+        let mut value = false;
+        let mut rest = self.0;
+        while !rest.is_empty() {
+            let length = rest.iter().take_while(|&&bit| bit == value).count();
+            write_into(sink, Uleb128(length))?;
+            written += 1;
+            value = !value;
+            rest = &rest[length..];
+        }
+
+        Ok(written)
+    }
+}
+
+/// Returns how many runs was written.
+impl WriteInto for &RleBitmap<'_> {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, RleBitmap(self.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+
+    #[test]
+    fn write_runs() {
+        let mut buffer = Vec::new();
+        let written =
+            write_into(&mut buffer, RleBitmap(&[false, false, true, true, true])).unwrap();
+        assert_eq!(written, 2);
+        assert_eq!(&buffer, &[0x02, 0x03]);
+    }
+
+    #[test]
+    fn write_long_run() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, RleBitmap(&[false; 200])).unwrap();
+        assert_eq!(written, 1);
+        assert_eq!(&buffer, &[0xC8, 0x01]);
+    }
+
+    #[test]
+    fn write_empty() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, RleBitmap(&[])).unwrap();
+        assert_eq!(written, 0);
+        assert!(buffer.is_empty());
+    }
+}
@@ -0,0 +1,95 @@
+use super::{write_into, BigEndian, LittleEndian, Plain, WriteInto};
+use std::io;
+
+/// Used to write fixed-point decimals as a scaled integer followed by the scale.
+///
+/// The decimal is `value / 10^scale`. The wrapped endianness wrapper determines the byte order
+/// of the scaled integer.
+///
+/// # Example
+///
+/// ```
+/// use write_into::{BigEndian, Scaled, write_into};
+///
+/// // 123.45
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, Scaled { value: BigEndian(12345), scale: 2 }).unwrap();
+/// assert_eq!(written, 9);
+/// assert_eq!(&buffer, &[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x30, 0x39, 0x02]);
+/// ```
+pub struct Scaled<E> {
+    /// Scaled integer wrapped into an endianness wrapper.
+    pub value: E,
+    /// Number of decimal digits after the point.
+    pub scale: u8,
+}
+
+macro_rules! impl_write_into {
+    ($($wrapper:ident)*) => {
+        $(
+            /// Returns how many bytes was written.
+            impl WriteInto for Scaled<$wrapper<i64>> {
+                type Output = usize;
+
+                fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+                    write_into(sink, self.value)?;
+                    write_into(sink, Plain(&self.scale))?;
+                    Ok(9)
+                }
+            }
+
+            /// Returns how many bytes was written.
+            impl WriteInto for &Scaled<$wrapper<i64>> {
+                type Output = usize;
+
+                fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+                    write_into(sink, Scaled { value: $wrapper(self.value.0), scale: self.scale })
+                }
+            }
+        )*
+    };
+}
+
+impl_write_into! {
+    BigEndian LittleEndian
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+
+    #[test]
+    fn write_cents_le() {
+        let mut buffer = Vec::new();
+        let written = write_into(
+            &mut buffer,
+            Scaled {
+                value: LittleEndian(12345),
+                scale: 2,
+            },
+        )
+        .unwrap();
+        assert_eq!(written, 9);
+        assert_eq!(
+            &buffer,
+            &[0x39, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02]
+        );
+    }
+
+    #[test]
+    fn write_negative_be() {
+        let mut buffer = Vec::new();
+        write_into(
+            &mut buffer,
+            &Scaled {
+                value: BigEndian(-1),
+                scale: 4,
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            &buffer,
+            &[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x04]
+        );
+    }
+}
@@ -1,12 +1,16 @@
-use super::{write_into, WriteInto};
-use std::io;
-use std::iter::{ExactSizeIterator, IntoIterator};
+use super::{io, write_into, WriteInto};
+#[cfg(feature = "alloc")]
+use super::{BigEndian, Plain};
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+use core::iter::{ExactSizeIterator, IntoIterator};
 
 /// Used to write values from [`IntoIterator`].
 ///
 /// # Example
 ///
-/// ```
+#[cfg_attr(feature = "alloc", doc = "```")]
+#[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
 /// use write_into::{BigEndian, Sequence, write_into};
 ///
 /// let mut buffer = Vec::new();
@@ -58,7 +62,8 @@ where
 ///
 /// # Example
 ///
-/// ```
+#[cfg_attr(feature = "alloc", doc = "```")]
+#[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
 /// use write_into::{BigEndian, SizedSequence, write_into};
 ///
 /// let mut buffer = Vec::new();
@@ -118,3 +123,358 @@ where
         write_into(sink, SizedSequence(self.0, self.1))
     }
 }
+
+/// Used to write values from [`IntoIterator`] padded to a fixed stride.
+///
+/// The second field is the stride and the third one is a byte used for padding. Writing an item
+/// whose representation exceeds the stride fails with [`io::ErrorKind::InvalidInput`] (the items
+/// preceding it remain written).
+///
+/// # Example
+///
+/// ```
+/// use write_into::{BigEndian, StridedSequence, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, StridedSequence(&[
+///     BigEndian(0xAABBu16),
+///     BigEndian(0xCCDDu16),
+/// ], 3, 0xFF)).unwrap();
+/// assert_eq!(written, 2);
+/// assert_eq!(&buffer, &[0xAA, 0xBB, 0xFF, 0xCC, 0xDD, 0xFF]);
+/// ```
+#[cfg(feature = "alloc")]
+pub struct StridedSequence<T>(pub T, pub usize, pub u8)
+where
+    T: IntoIterator,
+    T::Item: WriteInto;
+
+/// Returns how many items was written.
+#[cfg(feature = "alloc")]
+impl<T> WriteInto for StridedSequence<T>
+where
+    T: IntoIterator,
+    T::Item: WriteInto,
+{
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let mut buffer = Vec::with_capacity(self.1);
+        let mut written = 0;
+        for item in self.0 {
+            buffer.clear();
+            write_into(&mut buffer, item)?;
+            if buffer.len() > self.1 {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "item representation exceeds the stride",
+                ));
+            }
+
+            buffer.resize(self.1, self.2);
+            write_into(sink, Plain(&buffer[..]))?;
+            written += 1;
+        }
+
+        Ok(written)
+    }
+}
+
+/// Returns how many items was written.
+#[cfg(feature = "alloc")]
+impl<T> WriteInto for &StridedSequence<T>
+where
+    T: Copy + IntoIterator,
+    T::Item: WriteInto,
+{
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, StridedSequence(self.0, self.1, self.2))
+    }
+}
+
+/// Used to write vectors as sorted runs followed by their item count.
+///
+/// The items are written in ascending order, the count is written as big endian [`u64`].
+///
+/// # Example
+///
+/// ```
+/// use write_into::{BigEndian, SortedRun, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, SortedRun(vec![
+///     BigEndian(3u8),
+///     BigEndian(1u8),
+///     BigEndian(2u8),
+/// ])).unwrap();
+/// assert_eq!(written, 3);
+/// assert_eq!(&buffer, &[0x01, 0x02, 0x03, 0, 0, 0, 0, 0, 0, 0, 0x03]);
+/// ```
+#[cfg(feature = "alloc")]
+pub struct SortedRun<T>(pub Vec<T>)
+where
+    T: Ord + WriteInto;
+
+/// Returns how many items was written.
+#[cfg(feature = "alloc")]
+impl<T> WriteInto for SortedRun<T>
+where
+    T: Ord + WriteInto,
+{
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let mut items = self.0;
+        items.sort();
+        let count = items.len();
+        for item in items {
+            write_into(sink, item)?;
+        }
+
+        write_into(sink, BigEndian(count as u64))?;
+        Ok(count)
+    }
+}
+
+/// Returns how many items was written.
+#[cfg(feature = "alloc")]
+impl<T> WriteInto for &SortedRun<T>
+where
+    T: Clone + Ord + WriteInto,
+{
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, SortedRun(self.0.clone()))
+    }
+}
+
+/// Used to write values from [`IntoIterator`] separated by the given value.
+///
+/// The separator is written between consecutive items only.
+///
+/// # Example
+///
+#[cfg_attr(feature = "alloc", doc = "```")]
+#[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+/// use write_into::{Plain, SeparatedSequence, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, SeparatedSequence(["a", "b", "c"].map(Plain), Plain(", "))).unwrap();
+/// assert_eq!(written, 3);
+/// assert_eq!(&buffer, b"a, b, c");
+/// ```
+pub struct SeparatedSequence<T, S>(pub T, pub S)
+where
+    T: IntoIterator,
+    T::Item: WriteInto,
+    S: Copy + WriteInto;
+
+/// Returns how many items was written.
+impl<T, S> WriteInto for SeparatedSequence<T, S>
+where
+    T: IntoIterator,
+    T::Item: WriteInto,
+    S: Copy + WriteInto,
+{
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let mut written = 0;
+        for item in self.0 {
+            if written != 0 {
+                write_into(sink, self.1)?;
+            }
+
+            write_into(sink, item)?;
+            written += 1;
+        }
+
+        Ok(written)
+    }
+}
+
+/// Returns how many items was written.
+impl<T, S> WriteInto for &SeparatedSequence<T, S>
+where
+    T: Copy + IntoIterator,
+    T::Item: WriteInto,
+    S: Copy + WriteInto,
+{
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, SeparatedSequence(self.0, self.1))
+    }
+}
+
+/// Used to write the same value several times.
+///
+/// # Example
+///
+#[cfg_attr(feature = "alloc", doc = "```")]
+#[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+/// use write_into::{Plain, Repeat, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, Repeat(Plain("ab"), 3)).unwrap();
+/// assert_eq!(written, 3);
+/// assert_eq!(&buffer, b"ababab");
+/// ```
+pub struct Repeat<T>(pub T, pub usize)
+where
+    T: Copy + WriteInto;
+
+/// Returns how many times the value was written.
+impl<T> WriteInto for Repeat<T>
+where
+    T: Copy + WriteInto,
+{
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        for _ in 0..self.1 {
+            write_into(sink, self.0)?;
+        }
+
+        Ok(self.1)
+    }
+}
+
+/// Returns how many times the value was written.
+impl<T> WriteInto for &Repeat<T>
+where
+    T: Copy + WriteInto,
+{
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, Repeat(self.0, self.1))
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::super::*;
+
+    #[test]
+    fn write_strided_when_items_are_shorter() {
+        let mut buffer = Vec::new();
+        let written = write_into(
+            &mut buffer,
+            StridedSequence(["a", "bc"].map(Plain), 4, 0x00),
+        )
+        .unwrap();
+        assert_eq!(written, 2);
+        assert_eq!(&buffer, b"a\0\0\0bc\0\0");
+    }
+
+    #[test]
+    fn write_strided_when_item_exceeds_stride() {
+        let mut buffer = Vec::new();
+        let error = write_into(
+            &mut buffer,
+            StridedSequence(["ab", "cde"].map(Plain), 2, 0x00),
+        )
+        .unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(&buffer, b"ab");
+    }
+
+    #[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+    struct Key(u16);
+
+    impl WriteInto for Key {
+        type Output = usize;
+
+        fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+            write_into(sink, BigEndian(self.0))
+        }
+    }
+
+    #[test]
+    fn write_sorted_run() {
+        let run = SortedRun(vec![Key(0x0300), Key(0x0001), Key(0x0200)]);
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, &run).unwrap();
+        assert_eq!(written, 3);
+        assert_eq!(
+            &buffer,
+            &[0x00, 0x01, 0x02, 0x00, 0x03, 0x00, 0, 0, 0, 0, 0, 0, 0, 0x03]
+        );
+
+        let mut again = Vec::new();
+        write_into(
+            &mut again,
+            SortedRun(vec![Key(0x0200), Key(0x0300), Key(0x0001)]),
+        )
+        .unwrap();
+        assert_eq!(&again, &buffer);
+    }
+
+    #[test]
+    fn write_empty_sorted_run() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, SortedRun(Vec::<Key>::new())).unwrap();
+        assert_eq!(written, 0);
+        assert_eq!(&buffer, &[0; 8]);
+    }
+
+    #[test]
+    fn write_separated() {
+        let items = [
+            BigEndian(0x0102u16),
+            BigEndian(0x0304u16),
+            BigEndian(0x0506u16),
+        ];
+        let mut buffer = Vec::new();
+        let written = write_into(
+            &mut buffer,
+            SeparatedSequence(&items, Plain(b", " as &[u8])),
+        )
+        .unwrap();
+        assert_eq!(written, 3);
+        assert_eq!(
+            &buffer,
+            &[0x01, 0x02, b',', b' ', 0x03, 0x04, b',', b' ', 0x05, 0x06]
+        );
+    }
+
+    #[test]
+    fn write_separated_single_item() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, SeparatedSequence([Plain("a")], Plain(","))).unwrap();
+        assert_eq!(written, 1);
+        assert_eq!(&buffer, b"a");
+    }
+
+    #[test]
+    fn write_separated_empty() {
+        let mut buffer = Vec::new();
+        let written = write_into(
+            &mut buffer,
+            SeparatedSequence(Vec::<Plain<&str>>::new(), Plain(",")),
+        )
+        .unwrap();
+        assert_eq!(written, 0);
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn write_repeat() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, Repeat(BigEndian(0xDEADu16), 3)).unwrap();
+        assert_eq!(written, 3);
+        assert_eq!(&buffer, &[0xDE, 0xAD, 0xDE, 0xAD, 0xDE, 0xAD]);
+    }
+
+    #[test]
+    fn write_repeat_zero_times() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, Repeat(BigEndian(0xDEADu16), 0)).unwrap();
+        assert_eq!(written, 0);
+        assert!(buffer.is_empty());
+    }
+}
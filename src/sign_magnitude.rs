@@ -0,0 +1,94 @@
+use super::{write_into, WriteInto};
+use std::io;
+
+/// Used to write signed integers in sign-magnitude form.
+///
+/// The sign is stored in the highest bit and the magnitude in the remaining ones. Zero is always
+/// written as positive zero, since negative zero has no two's complement counterpart.
+/// [`i32::MIN`] has no sign-magnitude representation, so writing it fails with
+/// [`io::ErrorKind::InvalidInput`].
+///
+/// # Example
+///
+/// ```
+/// use write_into::{BigEndian, SignMagnitude, write_into};
+///
+/// let mut buffer = Vec::new();
+/// write_into(&mut buffer, SignMagnitude(BigEndian, -5)).unwrap();
+/// assert_eq!(&buffer, &[0x80, 0x00, 0x00, 0x05]);
+/// ```
+pub struct SignMagnitude<S, F>(pub F, pub i32)
+where
+    S: WriteInto,
+    F: FnOnce(u32) -> S;
+
+impl<S, F> WriteInto for SignMagnitude<S, F>
+where
+    S: WriteInto,
+    F: FnOnce(u32) -> S,
+{
+    type Output = ();
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        if self.1 == i32::MIN {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "i32::MIN has no sign-magnitude representation",
+            ));
+        }
+
+        let sign = if self.1 < 0 { 0x8000_0000 } else { 0 };
+        write_into(sink, (self.0)(sign | self.1.unsigned_abs()))?;
+        Ok(())
+    }
+}
+
+impl<S, F> WriteInto for &SignMagnitude<S, F>
+where
+    S: WriteInto,
+    F: Copy + FnOnce(u32) -> S,
+{
+    type Output = ();
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, SignMagnitude(self.0, self.1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use test_case::test_case;
+    use validators::vec;
+
+    mod validators {
+        pub fn vec(expected: &[u8]) -> impl FnOnce(Vec<u8>) {
+            let expected = expected.to_vec();
+            move |actual| assert_eq!(&expected, &actual)
+        }
+    }
+
+    #[test_case(  5 => using vec(&[ 0x00, 0x00, 0x00, 0x05 ]); "when       5" )]
+    #[test_case( -5 => using vec(&[ 0x80, 0x00, 0x00, 0x05 ]); "when minus 5" )]
+    #[test_case(  0 => using vec(&[ 0x00, 0x00, 0x00, 0x00 ]); "when       0" )]
+    fn write_i32_be(number: i32) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        write_into(&mut buffer, SignMagnitude(BigEndian, number)).unwrap();
+        buffer
+    }
+
+    #[test]
+    fn write_i32_le() {
+        let mut buffer = Vec::new();
+        write_into(&mut buffer, SignMagnitude(LittleEndian, -5)).unwrap();
+        assert_eq!(&buffer, &[0x05, 0x00, 0x00, 0x80]);
+    }
+
+    #[test]
+    fn write_i32_min() {
+        let mut buffer = Vec::new();
+        let error = write_into(&mut buffer, SignMagnitude(BigEndian, i32::MIN)).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert!(buffer.is_empty());
+    }
+}
@@ -1,5 +1,8 @@
-use super::{write_into, Plain, WriteInto};
-use std::io;
+#[cfg(feature = "std")]
+use super::CountingWriter;
+use super::{io, write_into, BigEndian, LittleEndian, Plain, Uleb128, WriteInto};
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
 
 /// Used to write values prepended with size of their representation.
 ///
@@ -9,7 +12,7 @@ use std::io;
 /// use write_into::{Sized, Plain, Uleb128, write_into};
 ///
 /// let mut buffer = Vec::new();
-/// let written = write_into(&mut buffer, Sized(Uleb128, Plain("Hello, Sailor!"))).unwrap();
+/// let (written, ()) = write_into(&mut buffer, Sized(Uleb128, Plain("Hello, Sailor!"))).unwrap();
 /// assert_eq!(written, 14);
 /// assert_eq!(&buffer, b"\x0EHello, Sailor!");
 /// ```
@@ -19,29 +22,201 @@ where
     S: WriteInto,
     F: FnOnce(usize) -> S;
 
-/// Returns how many bytes was taken by the representation of `T`.
+/// Returns how many bytes was taken by the representation of `T` and the output of writing it.
 impl<T, S, F> WriteInto for Sized<T, S, F>
 where
     T: WriteInto,
     S: WriteInto,
     F: FnOnce(usize) -> S,
 {
-    type Output = usize;
+    type Output = (usize, T::Output);
 
     fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
         let mut buffer = Vec::new();
-        write_into(&mut buffer, self.1)?;
+        let output = write_into(&mut buffer, self.1)?;
         let written = buffer.len();
 
         write_into(sink, (self.0)(written))?;
         write_into(sink, Plain(&buffer[..]))?;
 
+        Ok((written, output))
+    }
+}
+
+/// Returns how many bytes was taken by the representation of `T` and the output of writing it.
+impl<T, S, F> WriteInto for &Sized<T, S, F>
+where
+    T: Copy + WriteInto,
+    S: WriteInto,
+    F: Copy + FnOnce(usize) -> S,
+{
+    type Output = (usize, T::Output);
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, Sized(self.0, self.1))
+    }
+}
+
+/// Used to write values prepended with size of their representation without buffering it
+/// _(requires `std` feature)_.
+///
+/// Unlike [`Sized`], the value is written twice: first into a [`CountingWriter`] discarding the
+/// bytes to learn the size, then into the I/O sink right after the size. This trades the memory
+/// needed for the buffer for writing the value again, so its representation must not change
+/// between the writes.
+///
+/// # Example
+///
+/// ```
+/// use write_into::{StreamedSized, Plain, Uleb128, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let (written, ()) = write_into(&mut buffer, StreamedSized(Uleb128, Plain("Hello"))).unwrap();
+/// assert_eq!(written, 5);
+/// assert_eq!(&buffer, b"\x05Hello");
+/// ```
+#[cfg(feature = "std")]
+pub struct StreamedSized<T, S, F>(pub F, pub T)
+where
+    T: Copy + WriteInto,
+    S: WriteInto,
+    F: FnOnce(usize) -> S;
+
+/// Returns how many bytes was taken by the representation of `T` and the output of writing it.
+#[cfg(feature = "std")]
+impl<T, S, F> WriteInto for StreamedSized<T, S, F>
+where
+    T: Copy + WriteInto,
+    S: WriteInto,
+    F: FnOnce(usize) -> S,
+{
+    type Output = (usize, T::Output);
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let mut counter = CountingWriter::new(io::sink());
+        write_into(&mut counter, self.1)?;
+        let written = counter.count() as usize;
+
+        write_into(sink, (self.0)(written))?;
+        let output = write_into(sink, self.1)?;
+
+        Ok((written, output))
+    }
+}
+
+/// Returns how many bytes was taken by the representation of `T` and the output of writing it.
+#[cfg(feature = "std")]
+impl<T, S, F> WriteInto for &StreamedSized<T, S, F>
+where
+    T: Copy + WriteInto,
+    S: WriteInto,
+    F: Copy + FnOnce(usize) -> S,
+{
+    type Output = (usize, T::Output);
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, StreamedSized(self.0, self.1))
+    }
+}
+
+/// Used to write values prepended with size of their representation as big endian [`u32`].
+///
+/// A shorthand for `Sized(|size| BigEndian(size as u32), value)`, except that representations
+/// larger than [`u32::MAX`] bytes fail with [`io::ErrorKind::InvalidInput`] instead of having
+/// their size truncated.
+///
+/// # Example
+///
+/// ```
+/// use write_into::{U32BePrefixed, Plain, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, U32BePrefixed(Plain("Hello"))).unwrap();
+/// assert_eq!(written, 5);
+/// assert_eq!(&buffer, b"\0\0\0\x05Hello");
+/// ```
+pub struct U32BePrefixed<T>(pub T)
+where
+    T: WriteInto;
+
+/// Returns how many bytes was taken by the representation of `T`.
+impl<T> WriteInto for U32BePrefixed<T>
+where
+    T: WriteInto,
+{
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let mut buffer = Vec::new();
+        write_into(&mut buffer, self.0)?;
+        let written = buffer.len();
+        let size = u32::try_from(written).map_err(|_| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "representation is larger than u32::MAX bytes",
+            )
+        })?;
+
+        write_into(sink, BigEndian(size))?;
+        write_into(sink, Plain(&buffer[..]))?;
+
         Ok(written)
     }
 }
 
 /// Returns how many bytes was taken by the representation of `T`.
-impl<T, S, F> WriteInto for &Sized<T, S, F>
+impl<T> WriteInto for &U32BePrefixed<T>
+where
+    T: Copy + WriteInto,
+{
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, U32BePrefixed(self.0))
+    }
+}
+
+/// Used to write values followed by size of their representation.
+///
+/// # Example
+///
+/// ```
+/// use write_into::{FooterSized, LittleEndian, Plain, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, FooterSized(Plain("body"), |size| LittleEndian(size as u16))).unwrap();
+/// assert_eq!(written, 4);
+/// assert_eq!(&buffer, b"body\x04\x00");
+/// ```
+pub struct FooterSized<T, S, F>(pub T, pub F)
+where
+    T: WriteInto,
+    S: WriteInto,
+    F: FnOnce(usize) -> S;
+
+/// Returns how many bytes was taken by the representation of `T`.
+impl<T, S, F> WriteInto for FooterSized<T, S, F>
+where
+    T: WriteInto,
+    S: WriteInto,
+    F: FnOnce(usize) -> S,
+{
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let mut buffer = Vec::new();
+        write_into(&mut buffer, self.0)?;
+        let written = buffer.len();
+
+        write_into(sink, Plain(&buffer[..]))?;
+        write_into(sink, (self.1)(written))?;
+
+        Ok(written)
+    }
+}
+
+/// Returns how many bytes was taken by the representation of `T`.
+impl<T, S, F> WriteInto for &FooterSized<T, S, F>
 where
     T: Copy + WriteInto,
     S: WriteInto,
@@ -50,6 +225,349 @@ where
     type Output = usize;
 
     fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
-        write_into(sink, Sized(self.0, self.1))
+        write_into(sink, FooterSized(self.0, self.1))
+    }
+}
+
+/// Used to write values as type-length-value records.
+///
+/// The first field is the type code wrapped into [`BigEndian`] or [`LittleEndian`]. The type code
+/// and the [`u16`] size of the representation are written in the chosen byte order, followed by
+/// the representation. Writing a representation larger than `u16::MAX` bytes fails with
+/// [`io::ErrorKind::InvalidInput`].
+///
+/// # Example
+///
+/// ```
+/// use write_into::{BigEndian, Plain, Tlv, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, Tlv(BigEndian(0x0102), Plain("abc"))).unwrap();
+/// assert_eq!(written, 3);
+/// assert_eq!(&buffer, b"\x01\x02\x00\x03abc");
+/// ```
+pub struct Tlv<E, T>(pub E, pub T)
+where
+    T: WriteInto;
+
+macro_rules! impl_tlv {
+    ($($wrapper:ident)*) => {
+        $(
+            /// Returns how many bytes was taken by the representation of `T`.
+            impl<T> WriteInto for Tlv<$wrapper<u16>, T>
+            where
+                T: WriteInto,
+            {
+                type Output = usize;
+
+                fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+                    let mut buffer = Vec::new();
+                    write_into(&mut buffer, self.1)?;
+                    let written = buffer.len();
+                    let size = u16::try_from(written).map_err(|_| {
+                        io::Error::new(
+                            io::ErrorKind::InvalidInput,
+                            "representation is larger than u16::MAX bytes",
+                        )
+                    })?;
+
+                    write_into(sink, self.0)?;
+                    write_into(sink, $wrapper(size))?;
+                    write_into(sink, Plain(&buffer[..]))?;
+
+                    Ok(written)
+                }
+            }
+
+            /// Returns how many bytes was taken by the representation of `T`.
+            impl<T> WriteInto for &Tlv<$wrapper<u16>, T>
+            where
+                T: Copy + WriteInto,
+            {
+                type Output = usize;
+
+                fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+                    write_into(sink, Tlv($wrapper((self.0).0), self.1))
+                }
+            }
+        )*
+    };
+}
+
+impl_tlv! {
+    BigEndian LittleEndian
+}
+
+/// Used to write values from [`IntoIterator`] prepended with both their count and size of their
+/// representation.
+///
+/// The count and the size are written in LEB-128 format _(unsigned)_.
+///
+/// # Example
+///
+/// ```
+/// use write_into::{CountAndSizePrefixed, Plain, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, CountAndSizePrefixed(["ab", "cde"].map(Plain))).unwrap();
+/// assert_eq!(written, (2, 5));
+/// assert_eq!(&buffer, b"\x02\x05abcde");
+/// ```
+pub struct CountAndSizePrefixed<T>(pub T)
+where
+    T: IntoIterator,
+    T::Item: WriteInto;
+
+/// Returns how many items was written and how many bytes was taken by their representation.
+impl<T> WriteInto for CountAndSizePrefixed<T>
+where
+    T: IntoIterator,
+    T::Item: WriteInto,
+{
+    type Output = (usize, usize);
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let mut buffer = Vec::new();
+        let mut count = 0;
+        for item in self.0 {
+            write_into(&mut buffer, item)?;
+            count += 1;
+        }
+
+        let size = buffer.len();
+        write_into(sink, Uleb128(count))?;
+        write_into(sink, Uleb128(size))?;
+        write_into(sink, Plain(&buffer[..]))?;
+        Ok((count, size))
+    }
+}
+
+/// Returns how many items was written and how many bytes was taken by their representation.
+impl<T> WriteInto for &CountAndSizePrefixed<T>
+where
+    T: Copy + IntoIterator,
+    T::Item: WriteInto,
+{
+    type Output = (usize, usize);
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, CountAndSizePrefixed(self.0))
+    }
+}
+
+/// Used to write values prepended with size of their representation and padded to a boundary.
+///
+/// The first field maps the size to the prefix, the second one is the boundary and the third one
+/// is a byte used for padding. The size in the prefix does not include the padding. Writing with
+/// zero boundary fails with [`io::ErrorKind::InvalidInput`].
+///
+/// # Example
+///
+/// ```
+/// use write_into::{AlignedSizedChunk, BigEndian, Plain, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let chunk = AlignedSizedChunk(|size| BigEndian(size as u16), 4, 0x00, Plain("abcde"));
+/// let written = write_into(&mut buffer, chunk).unwrap();
+/// assert_eq!(written, 8);
+/// assert_eq!(&buffer, b"\x00\x05abcde\0\0\0");
+/// ```
+pub struct AlignedSizedChunk<T, S, F>(pub F, pub usize, pub u8, pub T)
+where
+    T: WriteInto,
+    S: WriteInto,
+    F: FnOnce(usize) -> S;
+
+/// Returns how many bytes was taken by the representation of `T` (including the padding).
+impl<T, S, F> WriteInto for AlignedSizedChunk<T, S, F>
+where
+    T: WriteInto,
+    S: WriteInto,
+    F: FnOnce(usize) -> S,
+{
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        if self.1 == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "alignment boundary is zero",
+            ));
+        }
+
+        let mut buffer = Vec::new();
+        write_into(&mut buffer, self.3)?;
+        let written = buffer.len();
+        buffer.resize(written.next_multiple_of(self.1), self.2);
+
+        write_into(sink, (self.0)(written))?;
+        write_into(sink, Plain(&buffer[..]))?;
+
+        Ok(buffer.len())
+    }
+}
+
+/// Returns how many bytes was taken by the representation of `T` (including the padding).
+impl<T, S, F> WriteInto for &AlignedSizedChunk<T, S, F>
+where
+    T: Copy + WriteInto,
+    S: WriteInto,
+    F: Copy + FnOnce(usize) -> S,
+{
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, AlignedSizedChunk(self.0, self.1, self.2, self.3))
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::super::*;
+
+    #[test]
+    fn write_sized_sequence() {
+        let mut buffer = Vec::new();
+        let written = write_into(
+            &mut buffer,
+            Sized(
+                Uleb128,
+                Sequence([BigEndian(0xAABBu16), BigEndian(0xCCDDu16)]),
+            ),
+        )
+        .unwrap();
+        assert_eq!(written, (4, 2));
+        assert_eq!(&buffer, &[0x04, 0xAA, 0xBB, 0xCC, 0xDD]);
+    }
+
+    #[test]
+    fn write_u32_be_prefixed() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, U32BePrefixed(BigEndian(0xAABBu16))).unwrap();
+        assert_eq!(written, 2);
+        assert_eq!(&buffer, &[0x00, 0x00, 0x00, 0x02, 0xAA, 0xBB]);
+    }
+
+    #[test]
+    fn write_u32_be_prefixed_empty() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, U32BePrefixed(Plain(""))).unwrap();
+        assert_eq!(written, 0);
+        assert_eq!(&buffer, &[0x00, 0x00, 0x00, 0x00]);
+    }
+
+    #[test]
+    fn write_footer_sized() {
+        let mut buffer = Vec::new();
+        let written = write_into(
+            &mut buffer,
+            FooterSized(
+                Sequence([BigEndian(0xAABBu16), BigEndian(0xCCDDu16)]),
+                |size| BigEndian(size as u32),
+            ),
+        )
+        .unwrap();
+        assert_eq!(written, 4);
+        assert_eq!(&buffer, &[0xAA, 0xBB, 0xCC, 0xDD, 0x00, 0x00, 0x00, 0x04]);
+    }
+
+    #[test]
+    fn write_footer_sized_failing_midway() {
+        let mut buffer = Vec::new();
+        let error = write_into(
+            &mut buffer,
+            FooterSized((Plain("abc"), PaddedUleb128(128u32, 1)), |size| {
+                BigEndian(size as u32)
+            }),
+        )
+        .unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn write_streamed_sized() {
+        let value = (BigEndian(0xAABBu16), Plain("cd"));
+        let mut expected = Vec::new();
+        let expected_output = write_into(&mut expected, Sized(Uleb128, value)).unwrap();
+
+        let mut buffer = Vec::new();
+        let output = write_into(&mut buffer, &StreamedSized(Uleb128, value)).unwrap();
+        assert_eq!(output, expected_output);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn write_tlv_be() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, Tlv(BigEndian(0xABCD), Plain(&[1u8, 2, 3]))).unwrap();
+        assert_eq!(written, 3);
+        assert_eq!(&buffer, &[0xAB, 0xCD, 0x00, 0x03, 0x01, 0x02, 0x03]);
+    }
+
+    #[test]
+    fn write_tlv_le() {
+        let mut buffer = Vec::new();
+        let written =
+            write_into(&mut buffer, Tlv(LittleEndian(0xABCD), Plain(&[1u8, 2, 3]))).unwrap();
+        assert_eq!(written, 3);
+        assert_eq!(&buffer, &[0xCD, 0xAB, 0x03, 0x00, 0x01, 0x02, 0x03]);
+    }
+
+    #[test]
+    fn write_tlv_overflow() {
+        let payload = vec![0u8; 65536];
+        let mut buffer = Vec::new();
+        let error = write_into(&mut buffer, Tlv(BigEndian(1), Plain(&payload[..]))).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn write_count_and_size_prefixed() {
+        let items = [1u32, 200, 70000].map(Uleb128);
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, CountAndSizePrefixed(&items)).unwrap();
+        assert_eq!(written, (3, 6));
+        assert_eq!(&buffer, &[0x03, 0x06, 0x01, 0xC8, 0x01, 0xF0, 0xA2, 0x04]);
+    }
+
+    #[test]
+    fn write_aligned_sized_chunk() {
+        let mut buffer = Vec::new();
+        let written = write_into(
+            &mut buffer,
+            AlignedSizedChunk(Uleb128, 4, 0xFF, Plain(b"\x01\x02\x03\x04\x05")),
+        )
+        .unwrap();
+        assert_eq!(written, 8);
+        assert_eq!(
+            &buffer,
+            &[0x05, 0x01, 0x02, 0x03, 0x04, 0x05, 0xFF, 0xFF, 0xFF]
+        );
+    }
+
+    #[test]
+    fn write_aligned_sized_chunk_with_zero_boundary() {
+        let mut buffer = Vec::new();
+        let error = write_into(
+            &mut buffer,
+            AlignedSizedChunk(Uleb128, 0, 0xFF, Plain("abc")),
+        )
+        .unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn write_aligned_sized_chunk_when_aligned() {
+        let mut buffer = Vec::new();
+        let written = write_into(
+            &mut buffer,
+            AlignedSizedChunk(Uleb128, 4, 0xFF, BigEndian(0xAABBCCDDu32)),
+        )
+        .unwrap();
+        assert_eq!(written, 4);
+        assert_eq!(&buffer, &[0x04, 0xAA, 0xBB, 0xCC, 0xDD]);
     }
 }
@@ -0,0 +1,148 @@
+use super::{write_into, NullPaddedField, OctalField, Plain, WriteInto};
+use std::io;
+
+/// Used to write USTAR headers of tar entries.
+///
+/// The header occupies a single 512-byte block. The checksum is the sum of all header bytes with
+/// the checksum field itself counted as spaces, written as six octal digits followed by a zero
+/// byte and a space. Writing a name longer than 100 bytes or a number not fitting into its field
+/// fails with [`io::ErrorKind::InvalidInput`].
+///
+/// # Example
+///
+/// ```
+/// use write_into::{TarHeader, write_into};
+///
+/// let header = TarHeader {
+///     name: "hello.txt",
+///     mode: 0o644,
+///     uid: 0,
+///     gid: 0,
+///     size: 12,
+///     mtime: 0,
+///     typeflag: b'0',
+/// };
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, header).unwrap();
+/// assert_eq!(written, 512);
+/// assert_eq!(&buffer[..9], b"hello.txt");
+/// assert_eq!(&buffer[257..265], b"ustar\x0000");
+/// ```
+pub struct TarHeader<'a> {
+    /// Path of the entry.
+    pub name: &'a str,
+    /// Permission bits (e.g. `0o644`).
+    pub mode: u32,
+    /// Numeric user id of the owner.
+    pub uid: u32,
+    /// Numeric group id of the owner.
+    pub gid: u32,
+    /// Size of the entry contents in bytes.
+    pub size: u64,
+    /// Modification time in seconds since the Unix epoch.
+    pub mtime: u64,
+    /// Type of the entry (e.g. `b'0'` for regular files or `b'5'` for directories).
+    pub typeflag: u8,
+}
+
+/// Returns how many bytes was written (the size of the block).
+impl WriteInto for TarHeader<'_> {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let octal = |value, width| OctalField { value, width };
+
+        let mut header = [0u8; 512];
+        let mut fields = &mut header[..];
+        write_into(&mut fields, NullPaddedField(self.name.as_bytes(), 100))?;
+        write_into(&mut fields, octal(self.mode as u64, 8))?;
+        write_into(&mut fields, octal(self.uid as u64, 8))?;
+        write_into(&mut fields, octal(self.gid as u64, 8))?;
+        write_into(&mut fields, octal(self.size, 12))?;
+        write_into(&mut fields, octal(self.mtime, 12))?;
+        write_into(&mut fields, Plain(b"        "))?;
+        write_into(&mut fields, Plain(&self.typeflag))?;
+        write_into(&mut fields, NullPaddedField(b"", 100))?;
+        write_into(&mut fields, Plain(b"ustar\x0000"))?;
+
+        let checksum = header.iter().map(|&byte| byte as u64).sum();
+        let mut field = &mut header[148..156];
+        write_into(&mut field, octal(checksum, 7))?;
+        write_into(&mut field, Plain(&b' '))?;
+
+        write_into(sink, Plain(&header))?;
+        Ok(header.len())
+    }
+}
+
+/// Returns how many bytes was written (the size of the block).
+impl WriteInto for &TarHeader<'_> {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(
+            sink,
+            TarHeader {
+                name: self.name,
+                mode: self.mode,
+                uid: self.uid,
+                gid: self.gid,
+                size: self.size,
+                mtime: self.mtime,
+                typeflag: self.typeflag,
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+
+    fn header(name: &str) -> TarHeader<'_> {
+        TarHeader {
+            name,
+            mode: 0o644,
+            uid: 1000,
+            gid: 1000,
+            size: 12,
+            mtime: 1_600_000_000,
+            typeflag: b'0',
+        }
+    }
+
+    #[test]
+    fn write_tar_header() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, &header("hello.txt")).unwrap();
+        assert_eq!(written, 512);
+        assert_eq!(buffer.len(), 512);
+        assert_eq!(&buffer[100..108], b"0000644\0");
+        assert_eq!(&buffer[108..116], b"0001750\0");
+        assert_eq!(&buffer[124..136], b"00000000014\0");
+        assert_eq!(&buffer[136..148], b"13727410000\0");
+        assert_eq!(&buffer[148..156], b"007667\0 ");
+        assert_eq!(buffer[156], b'0');
+    }
+
+    #[test]
+    fn checksum_counts_itself_as_spaces() {
+        let mut buffer = Vec::new();
+        write_into(&mut buffer, header("dir/file.bin")).unwrap();
+        let stored = std::str::from_utf8(&buffer[148..154]).unwrap();
+        let stored = u64::from_str_radix(stored, 8).unwrap();
+        buffer[148..156].fill(b' ');
+        let sum: u64 = buffer.iter().map(|&byte| byte as u64).sum();
+        assert_eq!(stored, sum);
+    }
+
+    #[test]
+    fn write_tar_header_with_long_name() {
+        let name = "a".repeat(101);
+        let mut buffer = Vec::new();
+        let error = write_into(&mut buffer, header(&name)).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert!(buffer.is_empty());
+    }
+}
@@ -0,0 +1,64 @@
+use super::{write_into, Plain, WriteInto};
+use std::io;
+
+/// Used to write values followed by `\r\n` (as in line-based text protocols).
+///
+/// The terminator is always appended, even if the representation already ends with it.
+///
+/// # Example
+///
+/// ```
+/// use write_into::{CrlfTerminated, Plain, write_into};
+///
+/// let mut buffer = Vec::new();
+/// write_into(&mut buffer, CrlfTerminated(Plain("EHLO example.com"))).unwrap();
+/// assert_eq!(&buffer, b"EHLO example.com\r\n");
+/// ```
+pub struct CrlfTerminated<T>(pub T)
+where
+    T: WriteInto;
+
+/// Returns the output of writing `T`.
+impl<T> WriteInto for CrlfTerminated<T>
+where
+    T: WriteInto,
+{
+    type Output = T::Output;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let output = write_into(sink, self.0)?;
+        write_into(sink, Plain("\r\n"))?;
+        Ok(output)
+    }
+}
+
+/// Returns the output of writing `T`.
+impl<T> WriteInto for &CrlfTerminated<T>
+where
+    T: Copy + WriteInto,
+{
+    type Output = T::Output;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, CrlfTerminated(self.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+
+    #[test]
+    fn write_header_line() {
+        let mut buffer = Vec::new();
+        write_into(&mut buffer, CrlfTerminated(Plain("Content-Length: 42"))).unwrap();
+        assert_eq!(&buffer, b"Content-Length: 42\r\n");
+    }
+
+    #[test]
+    fn write_already_terminated() {
+        let mut buffer = Vec::new();
+        write_into(&mut buffer, CrlfTerminated(Plain("\r\n"))).unwrap();
+        assert_eq!(&buffer, b"\r\n\r\n");
+    }
+}
@@ -0,0 +1,589 @@
+use super::{write_into, Plain, WriteInto};
+use std::io;
+use std::time::Duration;
+
+/// Used to write floats as the shortest decimal text that round-trips.
+///
+/// The text is produced by [`Display`](std::fmt::Display), so it never uses exponent notation and
+/// parsing it back with [`str::parse`] yields exactly the same value. Non-finite values are
+/// written as `NaN`, `inf` and `-inf`.
+///
+/// # Example
+///
+/// ```
+/// use write_into::{ShortestFloat, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, ShortestFloat(0.1)).unwrap();
+/// assert_eq!(written, 3);
+/// assert_eq!(&buffer, b"0.1");
+/// ```
+pub struct ShortestFloat(pub f64);
+
+/// Returns how many bytes was written.
+impl WriteInto for ShortestFloat {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let text = self.0.to_string();
+        write_into(sink, Plain(&*text))?;
+        Ok(text.len())
+    }
+}
+
+/// Returns how many bytes was written.
+impl WriteInto for &ShortestFloat {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, ShortestFloat(self.0))
+    }
+}
+
+/// Used to write numbers as zero-padded null-terminated octal text of a fixed width (as in TAR
+/// headers).
+///
+/// Writing a number whose digits do not fit into `width - 1` bytes fails with
+/// [`io::ErrorKind::InvalidInput`].
+///
+/// # Example
+///
+/// ```
+/// use write_into::{OctalField, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, OctalField { value: 0o644, width: 8 }).unwrap();
+/// assert_eq!(written, 8);
+/// assert_eq!(&buffer, b"0000644\0");
+/// ```
+pub struct OctalField {
+    /// Number to write.
+    pub value: u64,
+    /// Width of the field including the null terminator.
+    pub width: usize,
+}
+
+/// Returns how many bytes was written (the width of the field).
+impl WriteInto for OctalField {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let digits = self.width.saturating_sub(1);
+        let text = format!("{:0digits$o}", self.value);
+        if self.width == 0 || text.len() > digits {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "number does not fit into the octal field",
+            ));
+        }
+
+        write_into(sink, Plain(&*text))?;
+        write_into(sink, Plain(&0u8))?;
+        Ok(self.width)
+    }
+}
+
+/// Returns how many bytes was written (the width of the field).
+impl WriteInto for &OctalField {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(
+            sink,
+            OctalField {
+                value: self.value,
+                width: self.width,
+            },
+        )
+    }
+}
+
+/// Used to write characters as escaped Rust character literals.
+///
+/// The character is escaped with [`char::escape_default`] and surrounded by single quotes.
+///
+/// # Example
+///
+/// ```
+/// use write_into::{RustCharLiteral, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, RustCharLiteral('\n')).unwrap();
+/// assert_eq!(written, 4);
+/// assert_eq!(&buffer, b"'\\n'");
+/// ```
+pub struct RustCharLiteral(pub char);
+
+/// Returns how many bytes was written.
+impl WriteInto for RustCharLiteral {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let text = format!("'{}'", self.0.escape_default());
+        write_into(sink, Plain(&*text))?;
+        Ok(text.len())
+    }
+}
+
+/// Returns how many bytes was written.
+impl WriteInto for &RustCharLiteral {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, RustCharLiteral(self.0))
+    }
+}
+
+/// Used to write durations as `HH:MM:SS,mmm` timecodes (as in SRT subtitles).
+///
+/// Hours keep counting past 24 (and take more than two digits past 99). Sub-millisecond parts
+/// are truncated, so a timecode never points past the duration.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use write_into::{Timecode, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, Timecode(Duration::from_millis(3_661_500))).unwrap();
+/// assert_eq!(written, 12);
+/// assert_eq!(&buffer, b"01:01:01,500");
+/// ```
+pub struct Timecode(pub Duration);
+
+/// Returns how many bytes was written.
+impl WriteInto for Timecode {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let seconds = self.0.as_secs();
+        let text = format!(
+            "{:02}:{:02}:{:02},{:03}",
+            seconds / 3600,
+            seconds / 60 % 60,
+            seconds % 60,
+            self.0.subsec_millis(),
+        );
+
+        write_into(sink, Plain(&*text))?;
+        Ok(text.len())
+    }
+}
+
+/// Returns how many bytes was written.
+impl WriteInto for &Timecode {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, Timecode(self.0))
+    }
+}
+
+/// Used to write strings no longer than the given number of bytes.
+///
+/// The second field is the limit. Writing a longer string fails with
+/// [`io::ErrorKind::InvalidInput`] without writing anything.
+///
+/// # Example
+///
+/// ```
+/// use write_into::{BoundedStr, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, BoundedStr("Hello", 8)).unwrap();
+/// assert_eq!(written, 5);
+/// assert_eq!(&buffer, b"Hello");
+/// ```
+pub struct BoundedStr<'a>(pub &'a str, pub usize);
+
+/// Returns how many bytes was written.
+impl WriteInto for BoundedStr<'_> {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        if self.0.len() > self.1 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "string is longer than the limit",
+            ));
+        }
+
+        write_into(sink, Plain(self.0))?;
+        Ok(self.0.len())
+    }
+}
+
+/// Returns how many bytes was written.
+impl WriteInto for &BoundedStr<'_> {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, BoundedStr(self.0, self.1))
+    }
+}
+
+/// Used to write byte slices as uppercase hexadecimal text prefixed with `0x`.
+///
+/// # Example
+///
+/// ```
+/// use write_into::{PrefixedHex, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, PrefixedHex {
+///     bytes: &[0xDE, 0xAD, 0xBE, 0xEF],
+///     separator: Some(b':'),
+/// }).unwrap();
+/// assert_eq!(written, 13);
+/// assert_eq!(&buffer, b"0xDE:AD:BE:EF");
+/// ```
+pub struct PrefixedHex<'a> {
+    /// Bytes to write.
+    pub bytes: &'a [u8],
+    /// Byte written between the digit pairs.
+    pub separator: Option<u8>,
+}
+
+/// Returns how many bytes was written.
+impl WriteInto for PrefixedHex<'_> {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        const DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+        let mut text = Vec::with_capacity(2 + self.bytes.len() * 3);
+        text.extend_from_slice(b"0x");
+        for (i, &byte) in self.bytes.iter().enumerate() {
+            if let Some(separator) = self.separator.filter(|_| i != 0) {
+                text.push(separator);
+            }
+
+            text.push(DIGITS[(byte >> 4) as usize]);
+            text.push(DIGITS[(byte & 0x0F) as usize]);
+        }
+
+        write_into(sink, Plain(&text[..]))?;
+        Ok(text.len())
+    }
+}
+
+/// Returns how many bytes was written.
+impl WriteInto for &PrefixedHex<'_> {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(
+            sink,
+            PrefixedHex {
+                bytes: self.bytes,
+                separator: self.separator,
+            },
+        )
+    }
+}
+
+/// Used to write strings as 7-bit ASCII.
+///
+/// Each non-ASCII character is replaced by a single substitute byte given in the second field.
+/// [`Ascii7::new`] uses `?` as the substitute.
+///
+/// # Example
+///
+/// ```
+/// use write_into::{Ascii7, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, Ascii7::new("naïve café")).unwrap();
+/// assert_eq!(written, 10);
+/// assert_eq!(&buffer, b"na?ve caf?");
+///
+/// let mut buffer = Vec::new();
+/// write_into(&mut buffer, Ascii7("café", b'_')).unwrap();
+/// assert_eq!(&buffer, b"caf_");
+/// ```
+pub struct Ascii7<'a>(pub &'a str, pub u8);
+
+impl<'a> Ascii7<'a> {
+    /// Creates a wrapper replacing non-ASCII characters with `?`.
+    pub fn new(text: &'a str) -> Self {
+        Self(text, b'?')
+    }
+}
+
+/// Returns how many bytes was written.
+impl WriteInto for Ascii7<'_> {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let bytes: Vec<u8> = self
+            .0
+            .chars()
+            .map(|c| if c.is_ascii() { c as u8 } else { self.1 })
+            .collect();
+
+        write_into(sink, Plain(&bytes[..]))?;
+        Ok(bytes.len())
+    }
+}
+
+/// Returns how many bytes was written.
+impl WriteInto for &Ascii7<'_> {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, Ascii7(self.0, self.1))
+    }
+}
+
+/// Used to write characters repeated to a given width and followed by a newline.
+///
+/// # Example
+///
+/// ```
+/// use write_into::{FillLine, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, FillLine { ch: '-', width: 8 }).unwrap();
+/// assert_eq!(written, 9);
+/// assert_eq!(&buffer, b"--------\n");
+/// ```
+pub struct FillLine {
+    /// Character to repeat.
+    pub ch: char,
+    /// How many times the character is repeated.
+    pub width: usize,
+}
+
+/// Returns how many bytes was written (including the newline).
+impl WriteInto for FillLine {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let mut line = self.ch.to_string().repeat(self.width);
+        line.push('\n');
+        write_into(sink, Plain(&*line))?;
+        Ok(line.len())
+    }
+}
+
+/// Returns how many bytes was written (including the newline).
+impl WriteInto for &FillLine {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(
+            sink,
+            FillLine {
+                ch: self.ch,
+                width: self.width,
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use std::str::from_utf8;
+    use std::time::Duration;
+
+    fn write_float(number: f64) -> String {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, ShortestFloat(number)).unwrap();
+        assert_eq!(written, buffer.len());
+        let text = from_utf8(&buffer).unwrap().to_owned();
+        assert_eq!(text.parse::<f64>().unwrap().to_bits(), number.to_bits());
+        text
+    }
+
+    #[test]
+    fn write_one_tenth() {
+        assert_eq!(write_float(0.1), "0.1");
+    }
+
+    #[test]
+    fn write_huge() {
+        assert_eq!(write_float(1e300), format!("1{}", "0".repeat(300)));
+    }
+
+    #[test]
+    fn write_17_significant_digits() {
+        assert_eq!(write_float(0.1 + 0.2), "0.30000000000000004");
+    }
+
+    #[test]
+    fn write_octal_field_exactly() {
+        let mut buffer = Vec::new();
+        let written = write_into(
+            &mut buffer,
+            OctalField {
+                value: 0o7777777,
+                width: 8,
+            },
+        )
+        .unwrap();
+        assert_eq!(written, 8);
+        assert_eq!(&buffer, b"7777777\0");
+    }
+
+    #[test]
+    fn write_octal_field_overflow() {
+        let mut buffer = Vec::new();
+        let error = write_into(
+            &mut buffer,
+            OctalField {
+                value: 0o10000000,
+                width: 8,
+            },
+        )
+        .unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert!(buffer.is_empty());
+    }
+
+    fn write_char_literal(character: char) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, RustCharLiteral(character)).unwrap();
+        assert_eq!(written, buffer.len());
+        buffer
+    }
+
+    #[test]
+    fn write_newline_literal() {
+        assert_eq!(write_char_literal('\n'), br"'\n'");
+    }
+
+    #[test]
+    fn write_quote_literal() {
+        assert_eq!(write_char_literal('\''), br"'\''");
+    }
+
+    #[test]
+    fn write_printable_literal() {
+        assert_eq!(write_char_literal('a'), b"'a'");
+    }
+
+    fn write_timecode(duration: Duration) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, Timecode(duration)).unwrap();
+        assert_eq!(written, buffer.len());
+        buffer
+    }
+
+    #[test]
+    fn write_timecode_hour() {
+        assert_eq!(
+            write_timecode(Duration::from_millis(3_661_500)),
+            b"01:01:01,500"
+        );
+    }
+
+    #[test]
+    fn write_timecode_over_day() {
+        assert_eq!(
+            write_timecode(Duration::from_secs(25 * 3600)),
+            b"25:00:00,000"
+        );
+    }
+
+    #[test]
+    fn write_timecode_truncates_submillis() {
+        assert_eq!(
+            write_timecode(Duration::from_micros(1_999_999)),
+            b"00:00:01,999"
+        );
+    }
+
+    #[test]
+    fn write_bounded_str_at_limit() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, BoundedStr("\u{1F980}", 4)).unwrap();
+        assert_eq!(written, 4);
+        assert_eq!(&buffer, "\u{1F980}".as_bytes());
+    }
+
+    #[test]
+    fn write_bounded_str_over_limit() {
+        let mut buffer = Vec::new();
+        let error = write_into(&mut buffer, BoundedStr("Hello", 4)).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert!(buffer.is_empty());
+    }
+
+    fn write_hex(bytes: &[u8], separator: Option<u8>) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, PrefixedHex { bytes, separator }).unwrap();
+        assert_eq!(written, buffer.len());
+        buffer
+    }
+
+    #[test]
+    fn write_hex_without_separator() {
+        assert_eq!(write_hex(&[0xDE, 0xAD, 0xBE, 0xEF], None), b"0xDEADBEEF");
+    }
+
+    #[test]
+    fn write_hex_with_colon() {
+        assert_eq!(write_hex(&[0x00, 0x0F, 0xF0], Some(b':')), b"0x00:0F:F0");
+    }
+
+    #[test]
+    fn write_hex_empty() {
+        assert_eq!(write_hex(&[], Some(b':')), b"0x");
+    }
+
+    #[test]
+    fn write_ascii7_when_ascii() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, Ascii7::new("Hello, World!")).unwrap();
+        assert_eq!(written, 13);
+        assert_eq!(&buffer, b"Hello, World!");
+    }
+
+    #[test]
+    fn write_ascii7_when_accented() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, Ascii7::new("Crème brûlée")).unwrap();
+        assert_eq!(written, 12);
+        assert_eq!(&buffer, b"Cr?me br?l?e");
+    }
+
+    #[test]
+    fn write_ascii7_with_substitute() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, Ascii7("Ωmega 🦀", b'*')).unwrap();
+        assert_eq!(written, 7);
+        assert_eq!(&buffer, b"*mega *");
+    }
+
+    #[test]
+    fn write_ascii_fill_line() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, FillLine { ch: '=', width: 5 }).unwrap();
+        assert_eq!(written, 6);
+        assert_eq!(&buffer, b"=====\n");
+    }
+
+    #[test]
+    fn write_multibyte_fill_line() {
+        let mut buffer = Vec::new();
+        let written = write_into(
+            &mut buffer,
+            FillLine {
+                ch: '─', width: 4
+            },
+        )
+        .unwrap();
+        assert_eq!(written, 13);
+        assert_eq!(from_utf8(&buffer).unwrap(), "────\n");
+    }
+
+    #[test]
+    fn write_empty_fill_line() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, FillLine { ch: '*', width: 0 }).unwrap();
+        assert_eq!(written, 1);
+        assert_eq!(&buffer, b"\n");
+    }
+}
@@ -0,0 +1,48 @@
+use super::{io, write_into, BigEndian, WriteInto};
+use core::time::Duration;
+
+/// Writes the duration as 12 bytes: the whole seconds as big endian [`u64`] followed by the
+/// fractional part in nanoseconds (below `1_000_000_000`) as big endian [`u32`].
+impl WriteInto for Duration {
+    type Output = ();
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, BigEndian(self.as_secs()))?;
+        write_into(sink, BigEndian(self.subsec_nanos()))?;
+        Ok(())
+    }
+}
+
+/// Writes the duration as 12 bytes: the whole seconds as big endian [`u64`] followed by the
+/// fractional part in nanoseconds (below `1_000_000_000`) as big endian [`u32`].
+impl WriteInto for &Duration {
+    type Output = ();
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, *self)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn write_duration() {
+        let mut buffer = Vec::new();
+        write_into(&mut buffer, Duration::from_millis(1500)).unwrap();
+        assert_eq!(
+            &buffer,
+            &[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x1D, 0xCD, 0x65, 0x00]
+        );
+    }
+
+    #[test]
+    fn write_max_duration() {
+        let mut buffer = Vec::new();
+        write_into(&mut buffer, &Duration::new(u64::MAX, 999_999_999)).unwrap();
+        assert_eq!(&buffer[..8], &[0xFF; 8]);
+        assert_eq!(&buffer[8..], &999_999_999u32.to_be_bytes());
+    }
+}
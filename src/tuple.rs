@@ -0,0 +1,95 @@
+use super::{io, write_into, WriteInto};
+
+macro_rules! impl_write_into {
+    ($(($($element:ident)+))*) => {
+        $(
+            /// Writes the elements from left to right.
+            #[allow(non_snake_case)]
+            impl<$($element),+> WriteInto for ($($element,)+)
+            where
+                $($element: WriteInto,)+
+            {
+                type Output = ();
+
+                fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+                    let ($($element,)+) = self;
+                    $(write_into(sink, $element)?;)+
+                    Ok(())
+                }
+            }
+
+            /// Writes the elements from left to right.
+            #[allow(non_snake_case)]
+            impl<'a, $($element),+> WriteInto for &'a ($($element,)+)
+            where
+                $(&'a $element: WriteInto,)+
+            {
+                type Output = ();
+
+                fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+                    let ($($element,)+) = self;
+                    $(write_into(sink, $element)?;)+
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+impl_write_into! {
+    (A)
+    (A B)
+    (A B C)
+    (A B C D)
+    (A B C D E)
+    (A B C D E F)
+    (A B C D E F G)
+    (A B C D E F G H)
+    (A B C D E F G H I)
+    (A B C D E F G H I J)
+    (A B C D E F G H I J K)
+    (A B C D E F G H I J K L)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::super::*;
+
+    #[test]
+    fn write_pair() {
+        let mut buffer = Vec::new();
+        write_into(&mut buffer, (BigEndian(0xAABBu16), Plain("cd"))).unwrap();
+        assert_eq!(&buffer, b"\xAA\xBBcd");
+    }
+
+    #[test]
+    fn write_quadruple() {
+        let record = (
+            BigEndian(1u16),
+            Uleb128(300u32),
+            Plain("x"),
+            LittleEndian(0xCCDDu16),
+        );
+
+        let mut buffer = Vec::new();
+        write_into(&mut buffer, &record).unwrap();
+        assert_eq!(&buffer, &[0x00, 0x01, 0xAC, 0x02, b'x', 0xDD, 0xCC]);
+    }
+
+    #[test]
+    fn write_pair_when_inner_write_fails() {
+        let mut buffer = Vec::new();
+        let error = write_into(&mut buffer, (Plain("ab"), NullPaddedField(b"abc", 2))).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(&buffer, b"ab");
+    }
+
+    #[test]
+    fn write_quadruple_when_inner_write_fails() {
+        let record = (Plain("a"), BoundedStr("bcd", 2), Plain("e"), Plain("f"));
+        let mut buffer = Vec::new();
+        let error = write_into(&mut buffer, record).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(&buffer, b"a");
+    }
+}
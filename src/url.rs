@@ -0,0 +1,156 @@
+use super::{write_into, Plain, WriteInto};
+use std::io;
+
+/// Used to write key-value pairs as URL query strings.
+///
+/// Pairs are written as `key=value` joined by `&`. Keys and values are written as
+/// [`PercentEncoded`].
+///
+/// # Example
+///
+/// ```
+/// use write_into::{QueryString, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, QueryString([("q", "rust io"), ("page", "2")])).unwrap();
+/// assert_eq!(written, 18);
+/// assert_eq!(&buffer, b"q=rust%20io&page=2");
+/// ```
+pub struct QueryString<I>(pub I);
+
+/// Returns how many bytes was written.
+impl<'a, I> WriteInto for QueryString<I>
+where
+    I: IntoIterator<Item = (&'a str, &'a str)>,
+{
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let mut written = 0;
+        for (index, (key, value)) in self.0.into_iter().enumerate() {
+            if index != 0 {
+                write_into(sink, Plain("&"))?;
+                written += 1;
+            }
+
+            written += write_into(sink, PercentEncoded(key.as_bytes()))?;
+            write_into(sink, Plain("="))?;
+            written += 1;
+            written += write_into(sink, PercentEncoded(value.as_bytes()))?;
+        }
+
+        Ok(written)
+    }
+}
+
+/// Returns how many bytes was written.
+impl<'a, I> WriteInto for &QueryString<I>
+where
+    I: Copy + IntoIterator<Item = (&'a str, &'a str)>,
+{
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, QueryString(self.0))
+    }
+}
+
+/// Used to write byte slices in percent-encoding (URL encoding).
+///
+/// Unreserved characters (`A-Z`, `a-z`, `0-9`, `-`, `_`, `.` and `~`) are written as they are,
+/// other bytes are written as `%XX` with uppercase hexadecimal digits.
+///
+/// # Example
+///
+/// ```
+/// use write_into::{PercentEncoded, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, PercentEncoded(b"a b/c")).unwrap();
+/// assert_eq!(written, 9);
+/// assert_eq!(&buffer, b"a%20b%2Fc");
+/// ```
+pub struct PercentEncoded<'a>(pub &'a [u8]);
+
+/// Returns how many bytes was written.
+impl WriteInto for PercentEncoded<'_> {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        const DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+        let mut encoded = Vec::with_capacity(self.0.len());
+        for &byte in self.0 {
+            if byte.is_ascii_alphanumeric() || b"-_.~".contains(&byte) {
+                encoded.push(byte);
+            } else {
+                encoded.extend_from_slice(&[
+                    b'%',
+                    DIGITS[(byte >> 4) as usize],
+                    DIGITS[(byte & 0x0F) as usize],
+                ]);
+            }
+        }
+
+        write_into(sink, Plain(&encoded[..]))?;
+        Ok(encoded.len())
+    }
+}
+
+/// Returns how many bytes was written.
+impl WriteInto for &PercentEncoded<'_> {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, PercentEncoded(self.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use test_case::test_case;
+    use validators::vec;
+
+    mod validators {
+        pub fn vec(expected: &[u8]) -> impl FnOnce(Vec<u8>) {
+            let expected = expected.to_vec();
+            move |actual| assert_eq!(&expected, &actual)
+        }
+    }
+
+    #[test]
+    fn write_single_pair() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, QueryString([("key", "value")])).unwrap();
+        assert_eq!(written, 9);
+        assert_eq!(&buffer, b"key=value");
+    }
+
+    #[test]
+    fn write_pair_needing_encoding() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, &QueryString([("a&b", "1+1=2 ü")])).unwrap();
+        assert_eq!(written, 24);
+        assert_eq!(&buffer, b"a%26b=1%2B1%3D2%20%C3%BC");
+    }
+
+    #[test]
+    fn write_empty_query() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, QueryString(Vec::<(&str, &str)>::new())).unwrap();
+        assert_eq!(written, 0);
+        assert!(buffer.is_empty());
+    }
+
+    #[test_case( b" "        => using vec(b"%20"      ); "when space"      )]
+    #[test_case( b"/?#"      => using vec(b"%2F%3F%23"); "when reserved"   )]
+    #[test_case( b"a-_.~Z9"  => using vec(b"a-_.~Z9"  ); "when unreserved" )]
+    #[test_case( b"\x00\xFF" => using vec(b"%00%FF"   ); "when binary"     )]
+    fn write_percent_encoded(bytes: &[u8]) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, PercentEncoded(bytes)).unwrap();
+        assert_eq!(written, buffer.len());
+        buffer
+    }
+}
@@ -0,0 +1,148 @@
+use super::{write_into, BigEndian, LittleEndian, WriteInto};
+use std::io;
+
+/// Used to write strings in UTF-16 prepended with the number of code units.
+///
+/// The string is wrapped into [`BigEndian`] or [`LittleEndian`] (e.g. the latter for Windows
+/// resources), which determines the byte order of the count and the code units. Surrogate pairs
+/// count as two code units. Writing a string of more than [`u16::MAX`] code units fails with
+/// [`io::ErrorKind::InvalidInput`].
+///
+/// # Example
+///
+/// ```
+/// use write_into::{LenPrefixedUtf16, LittleEndian, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, LenPrefixedUtf16(LittleEndian("Hi"))).unwrap();
+/// assert_eq!(written, 6);
+/// assert_eq!(&buffer, &[0x02, 0x00, 0x48, 0x00, 0x69, 0x00]);
+/// ```
+pub struct LenPrefixedUtf16<E>(pub E);
+
+macro_rules! impl_len_prefixed {
+    ($($wrapper:ident)*) => {
+        $(
+            /// Returns how many bytes was written (including the count).
+            impl WriteInto for LenPrefixedUtf16<$wrapper<&str>> {
+                type Output = usize;
+
+                fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+                    let string = (self.0).0;
+                    let units = u16::try_from(string.encode_utf16().count()).map_err(|_| {
+                        io::Error::new(
+                            io::ErrorKind::InvalidInput,
+                            "string has more than u16::MAX code units",
+                        )
+                    })?;
+
+                    write_into(sink, $wrapper(units))?;
+                    let written = write_into(sink, Utf16($wrapper(string)))?;
+
+                    Ok(2 + written)
+                }
+            }
+
+            /// Returns how many bytes was written (including the count).
+            impl WriteInto for &LenPrefixedUtf16<$wrapper<&str>> {
+                type Output = usize;
+
+                fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+                    write_into(sink, LenPrefixedUtf16($wrapper((self.0).0)))
+                }
+            }
+        )*
+    };
+}
+
+impl_len_prefixed! {
+    BigEndian LittleEndian
+}
+
+/// Used to write strings in UTF-16.
+///
+/// The string is wrapped into [`BigEndian`] or [`LittleEndian`], which determines the byte order
+/// of the code units.
+///
+/// # Example
+///
+/// ```
+/// use write_into::{BigEndian, Utf16, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, Utf16(BigEndian("Hi"))).unwrap();
+/// assert_eq!(written, 4);
+/// assert_eq!(&buffer, &[0x00, 0x48, 0x00, 0x69]);
+/// ```
+pub struct Utf16<E>(pub E);
+
+macro_rules! impl_utf16 {
+    ($($wrapper:ident)*) => {
+        $(
+            /// Returns how many bytes was written (surrogate pairs take 4 bytes).
+            impl WriteInto for Utf16<$wrapper<&str>> {
+                type Output = usize;
+
+                fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+                    let mut written = 0;
+                    for unit in (self.0).0.encode_utf16() {
+                        written += write_into(sink, $wrapper(unit))?;
+                    }
+
+                    Ok(written)
+                }
+            }
+
+            /// Returns how many bytes was written (surrogate pairs take 4 bytes).
+            impl WriteInto for &Utf16<$wrapper<&str>> {
+                type Output = usize;
+
+                fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+                    write_into(sink, Utf16($wrapper((self.0).0)))
+                }
+            }
+        )*
+    };
+}
+
+impl_utf16! {
+    BigEndian LittleEndian
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+
+    #[test]
+    fn write_bmp_be() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, LenPrefixedUtf16(BigEndian("Ж1"))).unwrap();
+        assert_eq!(written, 6);
+        assert_eq!(&buffer, &[0x00, 0x02, 0x04, 0x16, 0x00, 0x31]);
+    }
+
+    #[test]
+    fn write_emoji_le() {
+        let mut buffer = Vec::new();
+        let written =
+            write_into(&mut buffer, &LenPrefixedUtf16(LittleEndian("\u{1F600}"))).unwrap();
+        assert_eq!(written, 6);
+        assert_eq!(&buffer, &[0x02, 0x00, 0x3D, 0xD8, 0x00, 0xDE]);
+    }
+
+    #[test]
+    fn write_astral_be() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, Utf16(BigEndian("A𝕏"))).unwrap();
+        assert_eq!(written, 6);
+        assert_eq!(&buffer, &[0x00, 0x41, 0xD8, 0x35, 0xDD, 0x4F]);
+    }
+
+    #[test]
+    fn write_astral_le() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, &Utf16(LittleEndian("A𝕏"))).unwrap();
+        assert_eq!(written, 6);
+        assert_eq!(&buffer, &[0x41, 0x00, 0x35, 0xD8, 0x4F, 0xDD]);
+    }
+}
@@ -0,0 +1,94 @@
+use super::{write_into, Plain, WriteInto};
+use ::uuid::Uuid;
+use std::io;
+
+/// Used to write UUIDs as 16 bytes _(requires `uuid` feature)_.
+///
+/// # Example
+///
+/// ```
+/// use uuid::Uuid;
+/// use write_into::{UuidBinary, write_into};
+///
+/// let uuid = Uuid::from_u128(0x67E55044_10B1_426F_9247_BB680E5FE0C8);
+/// let mut buffer = Vec::new();
+/// write_into(&mut buffer, UuidBinary(uuid)).unwrap();
+/// assert_eq!(&buffer, uuid.as_bytes());
+/// ```
+pub struct UuidBinary(pub Uuid);
+
+impl WriteInto for UuidBinary {
+    type Output = ();
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, Plain(self.0.as_bytes()))
+    }
+}
+
+impl WriteInto for &UuidBinary {
+    type Output = ();
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, UuidBinary(self.0))
+    }
+}
+
+/// Used to write UUIDs in hyphenated text form _(requires `uuid` feature)_.
+///
+/// # Example
+///
+/// ```
+/// use uuid::Uuid;
+/// use write_into::{UuidText, write_into};
+///
+/// let uuid = Uuid::from_u128(0x67E55044_10B1_426F_9247_BB680E5FE0C8);
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, UuidText(uuid)).unwrap();
+/// assert_eq!(written, 36);
+/// assert_eq!(&buffer, b"67e55044-10b1-426f-9247-bb680e5fe0c8");
+/// ```
+pub struct UuidText(pub Uuid);
+
+/// Returns how many bytes was written.
+impl WriteInto for UuidText {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let mut buffer = Uuid::encode_buffer();
+        let text = self.0.hyphenated().encode_lower(&mut buffer);
+        write_into(sink, Plain(&*text))?;
+        Ok(text.len())
+    }
+}
+
+/// Returns how many bytes was written.
+impl WriteInto for &UuidText {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, UuidText(self.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use super::*;
+
+    const UUID: Uuid = Uuid::from_u128(0xA1A2A3A4_B1B2_C1C2_D1D2_D3D4D5D6D7D8);
+
+    #[test]
+    fn write_binary() {
+        let mut buffer = Vec::new();
+        write_into(&mut buffer, UuidBinary(UUID)).unwrap();
+        assert_eq!(&buffer, UUID.as_bytes());
+    }
+
+    #[test]
+    fn write_text() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, UuidText(UUID)).unwrap();
+        assert_eq!(written, 36);
+        assert_eq!(buffer, UUID.to_string().into_bytes());
+    }
+}
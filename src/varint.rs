@@ -0,0 +1,452 @@
+use super::{write_into, BigEndian, LittleEndian, Plain, WriteInto};
+use std::io;
+
+/// Used to write values in Git packfile offset encoding.
+///
+/// Unlike LEB-128, the septets are written in big endian order and each continuation adds one to
+/// the remaining value, so every number has exactly one representation.
+///
+/// # Example
+///
+/// ```
+/// use write_into::{GitOffsetVarint, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, GitOffsetVarint(128)).unwrap();
+/// assert_eq!(written, 2);
+/// assert_eq!(&buffer, &[0x80, 0x00]);
+/// ```
+pub struct GitOffsetVarint(pub u64);
+
+/// Returns how many bytes was written.
+impl WriteInto for GitOffsetVarint {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let mut buffer = [0u8; 10];
+        let mut position = buffer.len() - 1;
+        let mut offset = self.0;
+        buffer[position] = offset as u8 & 0x7F;
+        offset >>= 7;
+        while offset != 0 {
+            offset -= 1;
+            position -= 1;
+            buffer[position] = 0x80 | (offset as u8 & 0x7F);
+            offset >>= 7;
+        }
+
+        write_into(sink, Plain(&buffer[position..]))?;
+        Ok(buffer.len() - position)
+    }
+}
+
+/// Returns how many bytes was written.
+impl WriteInto for &GitOffsetVarint {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, GitOffsetVarint(self.0))
+    }
+}
+
+/// Used to write values as MIDI variable-length quantities.
+///
+/// The septets are written in big endian order, so the quantity takes at most 4 bytes. Writing a
+/// value larger than `0x0FFFFFFF` fails with [`io::ErrorKind::InvalidInput`].
+///
+/// # Example
+///
+/// ```
+/// use write_into::{MidiVlq, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, MidiVlq(0x2000)).unwrap();
+/// assert_eq!(written, 2);
+/// assert_eq!(&buffer, &[0xC0, 0x00]);
+/// ```
+pub struct MidiVlq(pub u32);
+
+/// Returns how many bytes was written.
+impl WriteInto for MidiVlq {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        if self.0 > 0x0FFF_FFFF {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "value does not fit into MIDI variable-length quantity",
+            ));
+        }
+
+        let mut buffer = [0u8; 4];
+        let mut position = buffer.len() - 1;
+        let mut value = self.0;
+        buffer[position] = value as u8 & 0x7F;
+        value >>= 7;
+        while value != 0 {
+            position -= 1;
+            buffer[position] = 0x80 | (value as u8 & 0x7F);
+            value >>= 7;
+        }
+
+        write_into(sink, Plain(&buffer[position..]))?;
+        Ok(buffer.len() - position)
+    }
+}
+
+/// Returns how many bytes was written.
+impl WriteInto for &MidiVlq {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, MidiVlq(self.0))
+    }
+}
+
+/// Used to write numbers in the smallest of [`u8`], [`u16`] and [`u32`] preceded by a tag byte.
+///
+/// The tag is `0`, `1` or `2` respectively. The wrapped endianness wrapper determines the byte
+/// order of the number.
+///
+/// # Example
+///
+/// ```
+/// use write_into::{BigEndian, CompactUint, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, CompactUint(BigEndian(0xCAFE))).unwrap();
+/// assert_eq!(written, 3);
+/// assert_eq!(&buffer, &[0x01, 0xCA, 0xFE]);
+/// ```
+pub struct CompactUint<E>(pub E);
+
+macro_rules! impl_compact_uint {
+    ($($wrapper:ident)*) => {
+        $(
+            /// Returns how many bytes was written (including the tag).
+            impl WriteInto for CompactUint<$wrapper<u32>> {
+                type Output = usize;
+
+                fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+                    let value = (self.0).0;
+                    if let Ok(value) = u8::try_from(value) {
+                        write_into(sink, Plain(&[0, value]))?;
+                        Ok(2)
+                    } else if let Ok(value) = u16::try_from(value) {
+                        write_into(sink, Plain(&1u8))?;
+                        write_into(sink, $wrapper(value))?;
+                        Ok(3)
+                    } else {
+                        write_into(sink, Plain(&2u8))?;
+                        write_into(sink, $wrapper(value))?;
+                        Ok(5)
+                    }
+                }
+            }
+
+            /// Returns how many bytes was written (including the tag).
+            impl WriteInto for &CompactUint<$wrapper<u32>> {
+                type Output = usize;
+
+                fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+                    write_into(sink, CompactUint($wrapper((self.0).0)))
+                }
+            }
+        )*
+    };
+}
+
+impl_compact_uint! {
+    BigEndian LittleEndian
+}
+
+/// Used to write values in Bitcoin CompactSize encoding.
+///
+/// Values below `0xFD` take a single byte, larger ones are written in little endian byte order
+/// after a `0xFD`, `0xFE` or `0xFF` marker (for [`u16`], [`u32`] and [`u64`] respectively).
+/// Combined with [`Sized`](super::Sized) it prepends values with their size.
+///
+/// # Example
+///
+/// ```
+/// use write_into::{CompactSize, Plain, Sized, write_into};
+///
+/// let mut buffer = Vec::new();
+/// write_into(&mut buffer, Sized(|size| CompactSize(size as u64), Plain("script"))).unwrap();
+/// assert_eq!(&buffer, b"\x06script");
+/// ```
+pub struct CompactSize(pub u64);
+
+/// Returns how many bytes was written.
+impl WriteInto for CompactSize {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        if self.0 < 0xFD {
+            write_into(sink, Plain(&(self.0 as u8)))?;
+            Ok(1)
+        } else if let Ok(value) = u16::try_from(self.0) {
+            write_into(sink, Plain(&0xFDu8))?;
+            write_into(sink, LittleEndian(value))?;
+            Ok(3)
+        } else if let Ok(value) = u32::try_from(self.0) {
+            write_into(sink, Plain(&0xFEu8))?;
+            write_into(sink, LittleEndian(value))?;
+            Ok(5)
+        } else {
+            write_into(sink, Plain(&0xFFu8))?;
+            write_into(sink, LittleEndian(self.0))?;
+            Ok(9)
+        }
+    }
+}
+
+/// Returns how many bytes was written.
+impl WriteInto for &CompactSize {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, CompactSize(self.0))
+    }
+}
+
+/// Used to write signed integers in the fewest two's complement bytes preserving their sign.
+///
+/// The bytes are prefixed with their count. Wrapping the value into [`BigEndian`] writes the
+/// bytes from the most significant one (like DER integers without the tag), wrapping it into
+/// [`LittleEndian`] writes them in reverse.
+///
+/// # Example
+///
+/// ```
+/// use write_into::{BigEndian, MinimalSigned, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, MinimalSigned(BigEndian(128))).unwrap();
+/// assert_eq!(written, 3);
+/// assert_eq!(&buffer, &[0x02, 0x00, 0x80]);
+/// ```
+pub struct MinimalSigned<E>(pub E);
+
+/// Returns how many bytes was written (including the count).
+impl WriteInto for MinimalSigned<BigEndian<i64>> {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let value = (self.0).0;
+        let len = minimal_signed_len(value);
+        write_into(sink, Plain(&(len as u8)))?;
+        write_into(sink, Plain(&value.to_be_bytes()[8 - len..]))?;
+        Ok(len + 1)
+    }
+}
+
+/// Returns how many bytes was written (including the count).
+impl WriteInto for &MinimalSigned<BigEndian<i64>> {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, MinimalSigned(BigEndian((self.0).0)))
+    }
+}
+
+/// Returns how many bytes was written (including the count).
+impl WriteInto for MinimalSigned<LittleEndian<i64>> {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let value = (self.0).0;
+        let len = minimal_signed_len(value);
+        write_into(sink, Plain(&(len as u8)))?;
+        write_into(sink, Plain(&value.to_le_bytes()[..len]))?;
+        Ok(len + 1)
+    }
+}
+
+/// Returns how many bytes was written (including the count).
+impl WriteInto for &MinimalSigned<LittleEndian<i64>> {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, MinimalSigned(LittleEndian((self.0).0)))
+    }
+}
+
+fn minimal_signed_len(value: i64) -> usize {
+    let redundant = if value < 0 {
+        value.leading_ones()
+    } else {
+        value.leading_zeros()
+    };
+
+    // One of the leading bits has to stay to preserve the sign.
+    8 - (redundant as usize - 1) / 8
+}
+
+/// Used to write numbers as ID3v2 synchsafe integers.
+///
+/// The number is written in big endian byte order as four bytes of 7 bits each, with the most
+/// significant bit of every byte cleared. Numbers above `0x0FFFFFFF` fail with
+/// [`io::ErrorKind::InvalidInput`].
+///
+/// # Example
+///
+/// ```
+/// use write_into::{SyncSafeU32, write_into};
+///
+/// let mut buffer = Vec::new();
+/// write_into(&mut buffer, SyncSafeU32(0x80)).unwrap();
+/// assert_eq!(&buffer, &[0x00, 0x00, 0x01, 0x00]);
+/// ```
+pub struct SyncSafeU32(pub u32);
+
+impl WriteInto for SyncSafeU32 {
+    type Output = ();
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        if self.0 > 0x0FFF_FFFF {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "value does not fit into synchsafe integer",
+            ));
+        }
+
+        let bytes = [21, 14, 7, 0].map(|shift| (self.0 >> shift) as u8 & 0x7F);
+        write_into(sink, Plain(&bytes))?;
+        Ok(())
+    }
+}
+
+impl WriteInto for &SyncSafeU32 {
+    type Output = ();
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, SyncSafeU32(self.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use test_case::test_case;
+    use validators::vec;
+
+    mod validators {
+        pub fn vec(expected: &[u8]) -> impl FnOnce(Vec<u8>) {
+            let expected = expected.to_vec();
+            move |actual| assert_eq!(&expected, &actual)
+        }
+    }
+
+    #[test_case(       0 => using vec(&[ 0x00                   ]); "when       0" )]
+    #[test_case(     127 => using vec(&[ 0x7F                   ]); "when     127" )]
+    #[test_case(     128 => using vec(&[ 0x80, 0x00             ]); "when     128" )]
+    #[test_case(     300 => using vec(&[ 0x81, 0x2C             ]); "when     300" )]
+    #[test_case(   16511 => using vec(&[ 0xFF, 0x7F             ]); "when   16511" )]
+    #[test_case(   16512 => using vec(&[ 0x80, 0x80, 0x00       ]); "when   16512" )]
+    #[test_case( 2113663 => using vec(&[ 0xFF, 0xFF, 0x7F       ]); "when 2113663" )]
+    #[test_case( 2113664 => using vec(&[ 0x80, 0x80, 0x80, 0x00 ]); "when 2113664" )]
+    fn write_git_offset(offset: u64) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, GitOffsetVarint(offset)).unwrap();
+        assert_eq!(written, buffer.len());
+        buffer
+    }
+
+    #[test]
+    fn write_git_offset_max() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, GitOffsetVarint(u64::MAX)).unwrap();
+        assert_eq!(written, 10);
+    }
+
+    #[test_case( 0x0000000 => using vec(&[ 0x00                   ]); "when 0x0000000" )]
+    #[test_case( 0x0000040 => using vec(&[ 0x40                   ]); "when 0x0000040" )]
+    #[test_case( 0x000007F => using vec(&[ 0x7F                   ]); "when 0x000007F" )]
+    #[test_case( 0x0000080 => using vec(&[ 0x81, 0x00             ]); "when 0x0000080" )]
+    #[test_case( 0x0002000 => using vec(&[ 0xC0, 0x00             ]); "when 0x0002000" )]
+    #[test_case( 0x0003FFF => using vec(&[ 0xFF, 0x7F             ]); "when 0x0003FFF" )]
+    #[test_case( 0x0004000 => using vec(&[ 0x81, 0x80, 0x00       ]); "when 0x0004000" )]
+    #[test_case( 0x0100000 => using vec(&[ 0xC0, 0x80, 0x00       ]); "when 0x0100000" )]
+    #[test_case( 0x01FFFFF => using vec(&[ 0xFF, 0xFF, 0x7F       ]); "when 0x01FFFFF" )]
+    #[test_case( 0x0200000 => using vec(&[ 0x81, 0x80, 0x80, 0x00 ]); "when 0x0200000" )]
+    #[test_case( 0x8000000 => using vec(&[ 0xC0, 0x80, 0x80, 0x00 ]); "when 0x8000000" )]
+    #[test_case( 0xFFFFFFF => using vec(&[ 0xFF, 0xFF, 0xFF, 0x7F ]); "when 0xFFFFFFF" )]
+    fn write_midi_vlq(value: u32) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, MidiVlq(value)).unwrap();
+        assert_eq!(written, buffer.len());
+        buffer
+    }
+
+    #[test]
+    fn write_midi_vlq_overflow() {
+        let mut buffer = Vec::new();
+        let error = write_into(&mut buffer, MidiVlq(0x10000000)).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[test_case(       0x7F => using vec(&[ 0x00, 0x7F                   ]); "when       0x7F" )]
+    #[test_case(     0xCAFE => using vec(&[ 0x01, 0xFE, 0xCA             ]); "when     0xCAFE" )]
+    #[test_case( 0xCAFEBABE => using vec(&[ 0x02, 0xBE, 0xBA, 0xFE, 0xCA ]); "when 0xCAFEBABE" )]
+    fn write_compact_uint_le(value: u32) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, CompactUint(LittleEndian(value))).unwrap();
+        assert_eq!(written, buffer.len());
+        buffer
+    }
+
+    #[test_case(               0x10 => using vec(&[ 0x10                                                 ]); "when               0x10" )]
+    #[test_case(               0xFC => using vec(&[ 0xFC                                                 ]); "when               0xFC" )]
+    #[test_case(               0xFD => using vec(&[ 0xFD, 0xFD, 0x00                                     ]); "when               0xFD" )]
+    #[test_case(             0xFFFF => using vec(&[ 0xFD, 0xFF, 0xFF                                     ]); "when             0xFFFF" )]
+    #[test_case(            0x10000 => using vec(&[ 0xFE, 0x00, 0x00, 0x01, 0x00                         ]); "when            0x10000" )]
+    #[test_case( 0x0123456789ABCDEF => using vec(&[ 0xFF, 0xEF, 0xCD, 0xAB, 0x89, 0x67, 0x45, 0x23, 0x01 ]); "when 0x0123456789ABCDEF" )]
+    fn write_compact_size(value: u64) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, CompactSize(value)).unwrap();
+        assert_eq!(written, buffer.len());
+        buffer
+    }
+
+    #[test_case(                  0 => using vec(&[ 0x01, 0x00                                           ]); "when                   0" )]
+    #[test_case(                 -1 => using vec(&[ 0x01, 0xFF                                           ]); "when minus             1" )]
+    #[test_case(                127 => using vec(&[ 0x01, 0x7F                                           ]); "when                 127" )]
+    #[test_case(                128 => using vec(&[ 0x02, 0x00, 0x80                                     ]); "when                 128" )]
+    #[test_case(               -128 => using vec(&[ 0x01, 0x80                                           ]); "when minus           128" )]
+    #[test_case(               -129 => using vec(&[ 0x02, 0xFF, 0x7F                                     ]); "when minus           129" )]
+    #[test_case(            0x12345 => using vec(&[ 0x03, 0x01, 0x23, 0x45                               ]); "when             0x12345" )]
+    #[test_case(           i64::MAX => using vec(&[ 0x08, 0x7F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF ]); "when                 max" )]
+    #[test_case(           i64::MIN => using vec(&[ 0x08, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00 ]); "when                 min" )]
+    fn write_minimal_signed_be(value: i64) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, MinimalSigned(BigEndian(value))).unwrap();
+        assert_eq!(written, buffer.len());
+        buffer
+    }
+
+    #[test]
+    fn write_minimal_signed_le() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, MinimalSigned(LittleEndian(-129))).unwrap();
+        assert_eq!(written, 3);
+        assert_eq!(&buffer, &[0x02, 0x7F, 0xFF]);
+    }
+
+    #[test_case(         0 => using vec(&[ 0x00, 0x00, 0x00, 0x00 ]); "when         0" )]
+    #[test_case(       257 => using vec(&[ 0x00, 0x00, 0x02, 0x01 ]); "when       257" )]
+    #[test_case( 0xFFFFFFF => using vec(&[ 0x7F, 0x7F, 0x7F, 0x7F ]); "when 0xFFFFFFF" )]
+    fn write_sync_safe_u32(value: u32) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        write_into(&mut buffer, SyncSafeU32(value)).unwrap();
+        buffer
+    }
+
+    #[test]
+    fn write_sync_safe_u32_overflow() {
+        let mut buffer = Vec::new();
+        let error = write_into(&mut buffer, SyncSafeU32(0x10000000)).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert!(buffer.is_empty());
+    }
+}
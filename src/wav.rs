@@ -0,0 +1,271 @@
+use super::{write_into, CountingWriter, LittleEndian, Plain, WriteInto};
+use std::io;
+
+/// Used to write 16-bit PCM samples as WAV `data` chunks.
+///
+/// The chunk consists of the `data` id, the little endian [`u32`] length of the samples in bytes
+/// and the samples themselves in little endian byte order. Writing samples longer than
+/// `u32::MAX` bytes fails with [`io::ErrorKind::InvalidInput`].
+///
+/// # Example
+///
+/// ```
+/// use write_into::{WavDataChunk, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, WavDataChunk(&[1, -1])).unwrap();
+/// assert_eq!(written, 12);
+/// assert_eq!(&buffer, b"data\x04\x00\x00\x00\x01\x00\xFF\xFF");
+/// ```
+pub struct WavDataChunk<'a>(pub &'a [i16]);
+
+/// Returns how many bytes was written.
+impl WriteInto for WavDataChunk<'_> {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let length = self
+            .0
+            .len()
+            .checked_mul(2)
+            .and_then(|length| u32::try_from(length).ok())
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "samples are longer than u32::MAX bytes",
+                )
+            })?;
+
+        write_into(sink, Plain(b"data"))?;
+        write_into(sink, LittleEndian(length))?;
+        for &sample in self.0 {
+            write_into(sink, LittleEndian(sample))?;
+        }
+
+        Ok(8 + length as usize)
+    }
+}
+
+/// Returns how many bytes was written.
+impl WriteInto for &WavDataChunk<'_> {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, WavDataChunk(self.0))
+    }
+}
+
+/// Used to write headers of PCM WAV files (the RIFF header and the `fmt ` chunk).
+///
+/// The byte rate and the block align are computed from the other fields. The header is meant to
+/// be followed by the `data` chunk, e.g. written with [`WavDataChunk`]. Writing a header with the
+/// derived fields or the RIFF size overflowing [`u32`] fails with [`io::ErrorKind::InvalidInput`].
+///
+/// # Example
+///
+/// ```
+/// use write_into::{WavDataChunk, WavHeader, write_into};
+///
+/// let samples = [0, 0];
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, WavHeader {
+///     channels: 1,
+///     sample_rate: 8000,
+///     bits_per_sample: 16,
+///     data_len: 4,
+/// }).unwrap();
+/// write_into(&mut buffer, WavDataChunk(&samples)).unwrap();
+/// assert_eq!(written, 36);
+/// assert_eq!(buffer.len(), 48);
+/// assert_eq!(&buffer[..12], b"RIFF\x28\x00\x00\x00WAVE");
+/// ```
+pub struct WavHeader {
+    /// Number of interleaved channels.
+    pub channels: u16,
+    /// Number of samples per second in each channel.
+    pub sample_rate: u32,
+    /// Size of a single sample in bits.
+    pub bits_per_sample: u16,
+    /// Length of the `data` chunk payload in bytes.
+    pub data_len: u32,
+}
+
+/// Returns how many bytes was written.
+impl WriteInto for WavHeader {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let overflow = || {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "wav header field overflows its size",
+            )
+        };
+
+        let block_align = (self.bits_per_sample as u32).div_ceil(8) * self.channels as u32;
+        let block_align = u16::try_from(block_align).map_err(|_| overflow())?;
+        let byte_rate = self
+            .sample_rate
+            .checked_mul(block_align as u32)
+            .ok_or_else(overflow)?;
+        let riff_size = self.data_len.checked_add(36).ok_or_else(overflow)?;
+
+        write_into(sink, Plain(b"RIFF"))?;
+        write_into(sink, LittleEndian(riff_size))?;
+        write_into(sink, Plain(b"WAVEfmt "))?;
+        write_into(sink, LittleEndian(16u32))?;
+        write_into(sink, LittleEndian(1u16))?;
+        write_into(sink, LittleEndian(self.channels))?;
+        write_into(sink, LittleEndian(self.sample_rate))?;
+        write_into(sink, LittleEndian(byte_rate))?;
+        write_into(sink, LittleEndian(block_align))?;
+        write_into(sink, LittleEndian(self.bits_per_sample))?;
+        Ok(36)
+    }
+}
+
+/// Returns how many bytes was written.
+impl WriteInto for &WavHeader {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(
+            sink,
+            WavHeader {
+                channels: self.channels,
+                sample_rate: self.sample_rate,
+                bits_per_sample: self.bits_per_sample,
+                data_len: self.data_len,
+            },
+        )
+    }
+}
+
+/// Used to write values padded to an even number of bytes, as RIFF chunks require.
+///
+/// If the representation has an odd length, it is followed by a single zero byte.
+///
+/// # Example
+///
+/// ```
+/// use write_into::{Plain, RiffPadded, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, RiffPadded(Plain("abc"))).unwrap();
+/// assert_eq!(written, 4);
+/// assert_eq!(&buffer, b"abc\0");
+/// ```
+pub struct RiffPadded<T>(pub T)
+where
+    T: WriteInto;
+
+/// Returns how many bytes was written (including the pad byte).
+impl<T> WriteInto for RiffPadded<T>
+where
+    T: WriteInto,
+{
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let mut counter = CountingWriter::new(&mut *sink);
+        write_into(&mut counter, self.0)?;
+        let mut written = counter.count() as usize;
+
+        if !written.is_multiple_of(2) {
+            write_into(sink, Plain(&0u8))?;
+            written += 1;
+        }
+
+        Ok(written)
+    }
+}
+
+/// Returns how many bytes was written (including the pad byte).
+impl<T> WriteInto for &RiffPadded<T>
+where
+    T: Copy + WriteInto,
+{
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, RiffPadded(self.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+
+    #[test]
+    fn write_data_chunk() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, WavDataChunk(&[0, 0x1234, -2])).unwrap();
+        assert_eq!(written, 14);
+        assert_eq!(&buffer[..4], b"data");
+        assert_eq!(&buffer[4..8], &[0x06, 0x00, 0x00, 0x00]);
+        assert_eq!(&buffer[8..], &[0x00, 0x00, 0x34, 0x12, 0xFE, 0xFF]);
+    }
+
+    #[test]
+    fn write_empty_data_chunk() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, WavDataChunk(&[])).unwrap();
+        assert_eq!(written, 8);
+        assert_eq!(&buffer, b"data\0\0\0\0");
+    }
+
+    #[test]
+    fn write_cd_quality_header() {
+        let header = WavHeader {
+            channels: 2,
+            sample_rate: 44100,
+            bits_per_sample: 16,
+            data_len: 176400,
+        };
+
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, &header).unwrap();
+        assert_eq!(written, 36);
+        assert_eq!(&buffer[0..4], b"RIFF");
+        assert_eq!(&buffer[4..8], &176436u32.to_le_bytes());
+        assert_eq!(&buffer[8..16], b"WAVEfmt ");
+        assert_eq!(&buffer[16..20], &16u32.to_le_bytes());
+        assert_eq!(&buffer[20..22], &1u16.to_le_bytes());
+        assert_eq!(&buffer[22..24], &2u16.to_le_bytes());
+        assert_eq!(&buffer[24..28], &44100u32.to_le_bytes());
+        assert_eq!(&buffer[28..32], &176400u32.to_le_bytes());
+        assert_eq!(&buffer[32..34], &4u16.to_le_bytes());
+        assert_eq!(&buffer[34..36], &16u16.to_le_bytes());
+    }
+
+    #[test]
+    fn write_header_with_overflowing_riff_size() {
+        let header = WavHeader {
+            channels: 1,
+            sample_rate: 8000,
+            bits_per_sample: 8,
+            data_len: u32::MAX - 35,
+        };
+
+        let mut buffer = Vec::new();
+        let error = write_into(&mut buffer, header).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn write_riff_padded_odd() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, RiffPadded(Plain(b"\x01\x02\x03"))).unwrap();
+        assert_eq!(written, 4);
+        assert_eq!(&buffer, &[0x01, 0x02, 0x03, 0x00]);
+    }
+
+    #[test]
+    fn write_riff_padded_even() {
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, RiffPadded(WavDataChunk(&[1]))).unwrap();
+        assert_eq!(written, 10);
+        assert_eq!(&buffer, b"data\x02\x00\x00\x00\x01\x00");
+    }
+}
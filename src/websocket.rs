@@ -0,0 +1,157 @@
+use super::{write_into, BigEndian, Plain, WriteInto};
+use std::io;
+
+/// Used to write WebSocket frame headers (RFC 6455).
+///
+/// The payload length is written in the shortest of the 7-bit, 16-bit and 64-bit encodings.
+/// Writing an opcode larger than `0x0F` or a payload length with the most significant bit set
+/// fails with [`io::ErrorKind::InvalidInput`].
+///
+/// # Example
+///
+/// ```
+/// use write_into::{WsFrameHeader, XorMasked, write_into};
+///
+/// let mask = [0x37, 0xFA, 0x21, 0x3D];
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, WsFrameHeader {
+///     fin: true,
+///     opcode: 0x1,
+///     payload_len: 5,
+///     mask: Some(mask),
+/// }).unwrap();
+/// write_into(&mut buffer, XorMasked(b"Hello", mask)).unwrap();
+/// assert_eq!(written, 6);
+/// assert_eq!(&buffer, &[
+///     0x81, 0x85, 0x37, 0xFA, 0x21, 0x3D,
+///     0x7F, 0x9F, 0x4D, 0x51, 0x58,
+/// ]);
+/// ```
+pub struct WsFrameHeader {
+    /// Whether the frame is the final fragment of a message.
+    pub fin: bool,
+    /// Frame opcode (e.g. `0x1` for text or `0x2` for binary).
+    pub opcode: u8,
+    /// Length of the payload following the header.
+    pub payload_len: u64,
+    /// Masking key (present in frames sent by clients).
+    pub mask: Option<[u8; 4]>,
+}
+
+/// Returns how many bytes was written.
+impl WriteInto for WsFrameHeader {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        if self.opcode > 0x0F {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "opcode does not fit into 4 bits",
+            ));
+        }
+
+        if self.payload_len > i64::MAX as u64 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "payload length does not fit into 63 bits",
+            ));
+        }
+
+        let fin = if self.fin { 0x80 } else { 0x00 };
+        let masked = if self.mask.is_some() { 0x80 } else { 0x00 };
+        write_into(sink, Plain(&(fin | self.opcode)))?;
+        let mut written = 2;
+        if self.payload_len < 126 {
+            write_into(sink, Plain(&(masked | self.payload_len as u8)))?;
+        } else if self.payload_len <= u16::MAX as u64 {
+            write_into(sink, Plain(&(masked | 126)))?;
+            write_into(sink, BigEndian(self.payload_len as u16))?;
+            written += 2;
+        } else {
+            write_into(sink, Plain(&(masked | 127)))?;
+            write_into(sink, BigEndian(self.payload_len))?;
+            written += 8;
+        }
+
+        if let Some(mask) = self.mask {
+            write_into(sink, Plain(&mask))?;
+            written += 4;
+        }
+
+        Ok(written)
+    }
+}
+
+/// Returns how many bytes was written.
+impl WriteInto for &WsFrameHeader {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(
+            sink,
+            WsFrameHeader {
+                fin: self.fin,
+                opcode: self.opcode,
+                payload_len: self.payload_len,
+                mask: self.mask,
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+
+    fn write_header(payload_len: u64, mask: Option<[u8; 4]>) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        let written = write_into(
+            &mut buffer,
+            WsFrameHeader {
+                fin: true,
+                opcode: 0x2,
+                payload_len,
+                mask,
+            },
+        )
+        .unwrap();
+        assert_eq!(written, buffer.len());
+        buffer
+    }
+
+    #[test]
+    fn write_7_bit_length() {
+        assert_eq!(write_header(125, None), &[0x82, 0x7D]);
+    }
+
+    #[test]
+    fn write_16_bit_length() {
+        assert_eq!(write_header(126, None), &[0x82, 0x7E, 0x00, 0x7E]);
+        assert_eq!(write_header(65535, None), &[0x82, 0x7E, 0xFF, 0xFF]);
+    }
+
+    #[test]
+    fn write_64_bit_length() {
+        assert_eq!(
+            write_header(65536, Some([0x01, 0x02, 0x03, 0x04])),
+            &[0x82, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x01, 0x02, 0x03, 0x04]
+        );
+    }
+
+    #[test]
+    fn write_too_long_length() {
+        let mut buffer = Vec::new();
+        let error = write_into(
+            &mut buffer,
+            WsFrameHeader {
+                fin: true,
+                opcode: 0x2,
+                payload_len: 1 << 63,
+                mask: None,
+            },
+        )
+        .unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert!(buffer.is_empty());
+    }
+}
@@ -0,0 +1,72 @@
+use super::{write_into, Plain, WriteInto};
+use std::io;
+
+/// Used to write byte slices XOR-masked with a repeating 4-byte key (as in WebSocket frames).
+///
+/// # Example
+///
+/// ```
+/// use write_into::{XorMasked, write_into};
+///
+/// let mut buffer = Vec::new();
+/// let written = write_into(&mut buffer, XorMasked(b"Hello", [0x37, 0xFA, 0x21, 0x3D])).unwrap();
+/// assert_eq!(written, 5);
+/// assert_eq!(&buffer, &[0x7F, 0x9F, 0x4D, 0x51, 0x58]);
+/// ```
+pub struct XorMasked<'a>(pub &'a [u8], pub [u8; 4]);
+
+/// Returns how many bytes was written.
+impl WriteInto for XorMasked<'_> {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        let mut buffer = [0u8; 256];
+        for chunk in self.0.chunks(buffer.len()) {
+            // The chunk size is a multiple of the key size, so every chunk starts at key index 0.
+            for (i, (masked, byte)) in buffer.iter_mut().zip(chunk).enumerate() {
+                *masked = byte ^ self.1[i % 4];
+            }
+
+            write_into(sink, Plain(&buffer[..chunk.len()]))?;
+        }
+
+        Ok(self.0.len())
+    }
+}
+
+/// Returns how many bytes was written.
+impl WriteInto for &XorMasked<'_> {
+    type Output = usize;
+
+    fn write_into(self, sink: &mut impl io::Write) -> io::Result<Self::Output> {
+        write_into(sink, XorMasked(self.0, self.1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+
+    #[test]
+    fn write_masked_websocket_payload() {
+        // RFC 6455, section 5.7: a single-frame masked text message.
+        let mut buffer = Vec::new();
+        let written =
+            write_into(&mut buffer, XorMasked(b"Hello", [0x37, 0xFA, 0x21, 0x3D])).unwrap();
+        assert_eq!(written, 5);
+        assert_eq!(&buffer, &[0x7F, 0x9F, 0x4D, 0x51, 0x58]);
+    }
+
+    #[test]
+    fn write_masked_across_chunks() {
+        let data = vec![0xAAu8; 1000];
+        let key = [0x01, 0x02, 0x03, 0x04];
+        let mut buffer = Vec::new();
+        let written = write_into(&mut buffer, XorMasked(&data, key)).unwrap();
+        assert_eq!(written, 1000);
+        assert!(buffer
+            .iter()
+            .enumerate()
+            .all(|(i, &byte)| byte == 0xAA ^ key[i % 4]));
+    }
+}